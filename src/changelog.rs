@@ -0,0 +1,143 @@
+use crate::{
+    ChangeKind, ChangeTarget, DocsOptions, Schema, diff::endpoint_key, diff_schemas,
+    docs::docs_anchors,
+};
+
+/// Groups are ordered with metadata first, then tags, then untagged endpoints
+/// and finally objects.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Group {
+    General,
+    Tag(usize, String),
+    Other,
+    Objects,
+}
+
+impl Group {
+    fn heading(&self) -> &str {
+        match self {
+            Group::General => "General",
+            Group::Tag(_, tag) => tag,
+            Group::Other => "Other",
+            Group::Objects => "Objects",
+        }
+    }
+}
+
+fn format_link(label: &str, anchor: &str, docs_url: &str) -> String {
+    format!("[`{}`]({}#{})", label, docs_url, anchor)
+}
+
+fn generate_section(old: &Schema, new: &Schema, docs_url: &str, options: &DocsOptions) -> String {
+    let anchors = docs_anchors(new, options);
+
+    let mut tags: Vec<&str> = Vec::new();
+    for endpoint in new.endpoints.iter().chain(&old.endpoints) {
+        for tag in &endpoint.tags {
+            if !tags.contains(&tag.as_str()) {
+                tags.push(tag);
+            }
+        }
+    }
+
+    let mut groups: Vec<(Group, Vec<String>)> = Vec::new();
+    for change in diff_schemas(old, new) {
+        let (subject, change_groups) = match &change.target {
+            ChangeTarget::Schema => (None, vec![Group::General]),
+            ChangeTarget::Object(id) => {
+                let subject = match anchors.object(id) {
+                    Some(anchor) => format_link(id, anchor, docs_url),
+                    None => format!("`{}`", id),
+                };
+                (Some(subject), vec![Group::Objects])
+            }
+            ChangeTarget::Endpoint(method, path) => {
+                let key = (method.clone(), path.clone());
+                let label = format!("{} {}", method, path);
+
+                // Removed endpoints are no longer documented, so are not linked.
+                let (subject, endpoint) =
+                    match new.endpoints.iter().position(|e| endpoint_key(e) == key) {
+                        Some(index) => {
+                            let anchor = anchors.endpoint(index).unwrap_or_default();
+                            (
+                                format_link(&label, anchor, docs_url),
+                                Some(&new.endpoints[index]),
+                            )
+                        }
+                        None => (
+                            format!("`{}`", label),
+                            old.endpoints.iter().find(|e| endpoint_key(e) == key),
+                        ),
+                    };
+
+                let endpoint_groups = match endpoint {
+                    Some(endpoint) if !endpoint.tags.is_empty() => endpoint
+                        .tags
+                        .iter()
+                        .map(|tag| {
+                            let index = tags.iter().position(|t| t == tag).unwrap_or_default();
+                            Group::Tag(index, tag.clone())
+                        })
+                        .collect(),
+                    _ => vec![Group::Other],
+                };
+                (Some(subject), endpoint_groups)
+            }
+        };
+
+        let mut line = String::from("- ");
+        if change.kind == ChangeKind::Breaking {
+            line.push_str("**Breaking:** ");
+        }
+        if let Some(subject) = subject {
+            line.push_str(&format!("{}: ", subject));
+        }
+        line.push_str(&change.message);
+
+        for group in change_groups {
+            match groups.iter_mut().find(|(g, _)| *g == group) {
+                Some((_, lines)) => lines.push(line.clone()),
+                None => groups.push((group, vec![line.clone()])),
+            }
+        }
+    }
+    groups.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut output = format!("## {}\n\n", new.version.as_deref().unwrap_or("Unreleased"));
+    if groups.is_empty() {
+        output.push_str("No changes.\n\n");
+    }
+    for (group, lines) in groups {
+        output.push_str(&format!("### {}\n\n", group.heading()));
+        for line in lines {
+            output.push_str(&format!("{}\n", line));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Generates a Markdown changelog with a section per version, newest first,
+/// from specifications ordered oldest first. Entries link to the anchors in
+/// the documentation at `docs_url`, which may be empty if the changelog is
+/// placed in the same file.
+pub fn generate_changelog(schemas: &[Schema], docs_url: &str) -> String {
+    generate_changelog_with_options(schemas, docs_url, &DocsOptions::default())
+}
+
+/// Generates a Markdown changelog like [`generate_changelog`], linking to
+/// documentation that was rendered with `options`.
+pub fn generate_changelog_with_options(
+    schemas: &[Schema],
+    docs_url: &str,
+    options: &DocsOptions,
+) -> String {
+    let mut output = String::new();
+
+    for pair in schemas.windows(2).rev() {
+        output.push_str(&generate_section(&pair[0], &pair[1], docs_url, options));
+    }
+
+    output
+}
@@ -0,0 +1,258 @@
+use crate::{Object, Schema, Type};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiagramFormat {
+    /// A Mermaid `classDiagram`.
+    Mermaid,
+    /// A Mermaid `erDiagram`.
+    MermaidEr,
+    Dot,
+    PlantUml,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cardinality {
+    One,
+    ZeroOrOne,
+    Many,
+}
+
+impl Cardinality {
+    fn label(&self) -> &'static str {
+        match self {
+            Cardinality::One => "1",
+            Cardinality::ZeroOrOne => "0..1",
+            Cardinality::Many => "0..*",
+        }
+    }
+}
+
+/// A reference from a field of one object to another object.
+struct Edge<'a> {
+    from: &'a str,
+    to: &'a str,
+    field: &'a str,
+    cardinality: Cardinality,
+}
+
+fn find_references<'a>(
+    t: &'a Type,
+    cardinality: Cardinality,
+    found: &mut Vec<(&'a str, Cardinality)>,
+) {
+    match t {
+        Type::Reference(name) => found.push((name, cardinality)),
+        Type::Optional(inner) => {
+            let cardinality = match cardinality {
+                Cardinality::One => Cardinality::ZeroOrOne,
+                cardinality => cardinality,
+            };
+            find_references(inner, cardinality, found);
+        }
+        Type::Array(inner) => find_references(inner, Cardinality::Many, found),
+        Type::Mapping(key, value) => {
+            find_references(key, Cardinality::Many, found);
+            find_references(value, Cardinality::Many, found);
+        }
+        _ => {}
+    }
+}
+
+fn edges(schema: &Schema) -> Vec<Edge<'_>> {
+    let mut edges = Vec::new();
+
+    for object in &schema.objects {
+        for field in &object.fields {
+            let mut found = Vec::new();
+            find_references(&field.field_type, Cardinality::One, &mut found);
+
+            for (to, cardinality) in found {
+                edges.push(Edge {
+                    from: &object.id,
+                    to,
+                    field: &field.name,
+                    cardinality,
+                });
+            }
+        }
+    }
+
+    edges
+}
+
+fn generate_mermaid(schema: &Schema) -> String {
+    let mut output = String::from("classDiagram\n");
+
+    for object in &schema.objects {
+        if object.fields.is_empty() {
+            output.push_str(&format!("    class {}\n", object.id));
+            continue;
+        }
+
+        output.push_str(&format!("    class {} {{\n", object.id));
+        for field in &object.fields {
+            // Mermaid uses tildes for generics, and angle brackets are not
+            // allowed in member names.
+            let field_type = field.field_type.to_string().replace(['<', '>'], "~");
+            output.push_str(&format!("        +{} {}\n", field_type, field.name));
+        }
+        output.push_str("    }\n");
+    }
+
+    for edge in edges(schema) {
+        output.push_str(&format!(
+            "    {} --> \"{}\" {} : {}\n",
+            edge.from,
+            edge.cardinality.label(),
+            edge.to,
+            edge.field
+        ));
+    }
+
+    output
+}
+
+/// Formats a type as an `erDiagram` attribute type, which may only contain
+/// word characters, hyphens and brackets.
+fn er_type(t: &Type) -> String {
+    match t {
+        Type::Optional(inner) => er_type(inner),
+        Type::Array(inner) => format!("{}[]", er_type(inner)),
+        Type::Mapping(_, _) => "map".to_string(),
+        t => t.to_string(),
+    }
+}
+
+fn generate_mermaid_er(schema: &Schema) -> String {
+    let mut output = String::from("erDiagram\n");
+
+    for object in &schema.objects {
+        if object.fields.is_empty() {
+            output.push_str(&format!("    {}\n", object.id));
+            continue;
+        }
+
+        output.push_str(&format!("    {} {{\n", object.id));
+        for field in &object.fields {
+            let simple_type = er_type(&field.field_type);
+            let full_type = field.field_type.to_string();
+
+            if simple_type == full_type {
+                output.push_str(&format!("        {} {}\n", simple_type, field.name));
+            } else {
+                output.push_str(&format!(
+                    "        {} {} \"{}\"\n",
+                    simple_type, field.name, full_type
+                ));
+            }
+        }
+        output.push_str("    }\n");
+    }
+
+    for edge in edges(schema) {
+        let target = match edge.cardinality {
+            Cardinality::One => "||",
+            Cardinality::ZeroOrOne => "o|",
+            Cardinality::Many => "o{",
+        };
+        output.push_str(&format!(
+            "    {} ||--{} {} : \"{}\"\n",
+            edge.from, target, edge.to, edge.field
+        ));
+    }
+
+    output
+}
+
+/// Escapes text for use in a Graphviz record label.
+fn escape_record(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if matches!(c, '\\' | '"' | '{' | '}' | '|' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn format_dot_node(object: &Object) -> String {
+    let fields: String = object
+        .fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}: {}\\l",
+                escape_record(&field.name),
+                escape_record(&field.field_type.to_string())
+            )
+        })
+        .collect();
+
+    format!(
+        "    \"{}\" [label=\"{{{}|{}}}\"];\n",
+        object.id,
+        escape_record(&object.id),
+        fields
+    )
+}
+
+fn generate_dot(schema: &Schema) -> String {
+    let mut output = String::from("digraph Schema {\n    node [shape=record];\n\n");
+
+    for object in &schema.objects {
+        output.push_str(&format_dot_node(object));
+    }
+
+    if !schema.objects.is_empty() {
+        output.push('\n');
+    }
+    for edge in edges(schema) {
+        output.push_str(&format!(
+            "    \"{}\" -> \"{}\" [label=\"{}\", headlabel=\"{}\"];\n",
+            edge.from,
+            edge.to,
+            edge.field,
+            edge.cardinality.label()
+        ));
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+fn generate_plantuml(schema: &Schema) -> String {
+    let mut output = String::from("@startuml\n");
+
+    for object in &schema.objects {
+        output.push_str(&format!("class {} {{\n", object.id));
+        for field in &object.fields {
+            output.push_str(&format!("    {} : {}\n", field.name, field.field_type));
+        }
+        output.push_str("}\n");
+    }
+
+    for edge in edges(schema) {
+        output.push_str(&format!(
+            "{} --> \"{}\" {} : {}\n",
+            edge.from,
+            edge.cardinality.label(),
+            edge.to,
+            edge.field
+        ));
+    }
+
+    output.push_str("@enduml\n");
+    output
+}
+
+/// Generates a diagram of the objects in a specification, with an edge for
+/// every field that refers to another object.
+pub fn generate_diagram(schema: &Schema, format: DiagramFormat) -> String {
+    match format {
+        DiagramFormat::Mermaid => generate_mermaid(schema),
+        DiagramFormat::MermaidEr => generate_mermaid_er(schema),
+        DiagramFormat::Dot => generate_dot(schema),
+        DiagramFormat::PlantUml => generate_plantuml(schema),
+    }
+}
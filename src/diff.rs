@@ -0,0 +1,442 @@
+use crate::{Endpoint, Field, JsonValue, Object, Schema, Type};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChangeKind {
+    Breaking,
+    NonBreaking,
+    /// A change to descriptions, tags or examples only.
+    Documentation,
+}
+
+/// The part of a specification that a change applies to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeTarget {
+    /// The top-level metadata of the specification.
+    Schema,
+    Object(String),
+    /// The method and path of an endpoint.
+    Endpoint(String, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub target: ChangeTarget,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffFormat {
+    Text,
+    Json,
+    Markdown,
+}
+
+impl ChangeKind {
+    fn label(&self) -> &'static str {
+        match self {
+            ChangeKind::Breaking => "breaking",
+            ChangeKind::NonBreaking => "non-breaking",
+            ChangeKind::Documentation => "documentation",
+        }
+    }
+}
+
+impl ChangeTarget {
+    fn label(&self) -> String {
+        match self {
+            ChangeTarget::Schema => "specification".to_string(),
+            ChangeTarget::Object(id) => format!("object {}", id),
+            ChangeTarget::Endpoint(method, path) => format!("{} {}", method, path),
+        }
+    }
+}
+
+/// Normalises `{param}` path segments to `:param` so that both styles compare
+/// equal.
+pub(crate) fn endpoint_key(endpoint: &Endpoint) -> (String, String) {
+    let path = endpoint
+        .path
+        .split('/')
+        .map(
+            |segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some(name) => format!(":{}", name),
+                None => segment.to_string(),
+            },
+        )
+        .collect::<Vec<_>>()
+        .join("/");
+
+    (endpoint.method.to_uppercase(), path)
+}
+
+struct Differ {
+    changes: Vec<Change>,
+}
+
+impl Differ {
+    fn push(&mut self, kind: ChangeKind, target: &ChangeTarget, message: String) {
+        self.changes.push(Change {
+            kind,
+            target: target.clone(),
+            message,
+        });
+    }
+
+    fn diff_documentation<T: PartialEq>(
+        &mut self,
+        target: &ChangeTarget,
+        subject: &str,
+        old: &T,
+        new: &T,
+    ) {
+        if old != new {
+            self.push(
+                ChangeKind::Documentation,
+                target,
+                format!("Changed {}", subject),
+            );
+        }
+    }
+
+    /// Compares the type of a value that appears in both requests and
+    /// responses, so any change to it is breaking.
+    fn diff_type(&mut self, target: &ChangeTarget, subject: &str, old: &Type, new: &Type) {
+        if old == new {
+            return;
+        }
+
+        let message = match (old, new) {
+            (Type::Optional(inner), new) if inner.as_ref() == new => {
+                format!("Made {} required", subject)
+            }
+            (old, Type::Optional(inner)) if inner.as_ref() == old => {
+                format!("Made {} optional", subject)
+            }
+            _ => format!("Changed {} from `{}` to `{}`", subject, old, new),
+        };
+        self.push(ChangeKind::Breaking, target, message);
+    }
+
+    /// Compares the type of a value only sent by clients, where making it
+    /// optional cannot break them.
+    fn diff_input_type(&mut self, target: &ChangeTarget, subject: &str, old: &Type, new: &Type) {
+        match new {
+            Type::Optional(inner) if inner.as_ref() == old => self.push(
+                ChangeKind::NonBreaking,
+                target,
+                format!("Made {} optional", subject),
+            ),
+            _ => self.diff_type(target, subject, old, new),
+        }
+    }
+
+    fn diff_fields(
+        &mut self,
+        target: &ChangeTarget,
+        noun: &str,
+        old: &[Field],
+        new: &[Field],
+        input_only: bool,
+    ) {
+        for old_field in old {
+            let subject = format!("{} '{}'", noun, old_field.name);
+
+            if let Some(new_field) = new.iter().find(|f| f.name == old_field.name) {
+                self.diff_documentation(
+                    target,
+                    &format!("description of {}", subject),
+                    &old_field.description,
+                    &new_field.description,
+                );
+                self.diff_documentation(
+                    target,
+                    &format!("example of {}", subject),
+                    &old_field.example,
+                    &new_field.example,
+                );
+            }
+
+            match new.iter().find(|f| f.name == old_field.name) {
+                Some(new_field) if input_only => self.diff_input_type(
+                    target,
+                    &subject,
+                    &old_field.field_type,
+                    &new_field.field_type,
+                ),
+                Some(new_field) => self.diff_type(
+                    target,
+                    &subject,
+                    &old_field.field_type,
+                    &new_field.field_type,
+                ),
+                None => self.push(ChangeKind::Breaking, target, format!("Removed {}", subject)),
+            }
+        }
+
+        for new_field in new {
+            if old.iter().any(|f| f.name == new_field.name) {
+                continue;
+            }
+
+            if matches!(new_field.field_type, Type::Optional(_)) {
+                self.push(
+                    ChangeKind::NonBreaking,
+                    target,
+                    format!("Added optional {} '{}'", noun, new_field.name),
+                );
+            } else {
+                self.push(
+                    ChangeKind::Breaking,
+                    target,
+                    format!("Added required {} '{}'", noun, new_field.name),
+                );
+            }
+        }
+    }
+
+    fn diff_object(&mut self, old: &Object, new: &Object) {
+        let target = ChangeTarget::Object(new.id.clone());
+        self.diff_fields(&target, "field", &old.fields, &new.fields, false);
+        self.diff_documentation(&target, "example", &old.example, &new.example);
+    }
+
+    fn diff_endpoint(&mut self, old: &Endpoint, new: &Endpoint) {
+        let (method, path) = endpoint_key(new);
+        let target = ChangeTarget::Endpoint(method, path);
+
+        self.diff_documentation(&target, "description", &old.description, &new.description);
+        self.diff_documentation(&target, "tags", &old.tags, &new.tags);
+        self.diff_documentation(
+            &target,
+            "request body example",
+            &old.request_body_example,
+            &new.request_body_example,
+        );
+        self.diff_documentation(
+            &target,
+            "response examples",
+            &old.response_examples,
+            &new.response_examples,
+        );
+
+        self.diff_fields(&target, "path parameter", &old.params, &new.params, true);
+        self.diff_fields(&target, "query parameter", &old.query, &new.query, true);
+
+        match (&old.request_body, &new.request_body) {
+            (None, None) => {}
+            (Some(_), None) => self.push(
+                ChangeKind::Breaking,
+                &target,
+                "Removed request body".to_string(),
+            ),
+            (None, Some(Type::Optional(_))) => self.push(
+                ChangeKind::NonBreaking,
+                &target,
+                "Added optional request body".to_string(),
+            ),
+            (None, Some(_)) => self.push(
+                ChangeKind::Breaking,
+                &target,
+                "Added required request body".to_string(),
+            ),
+            (Some(old_type), Some(new_type)) => {
+                self.diff_input_type(&target, "request body", old_type, new_type)
+            }
+        }
+
+        for (status, old_type) in &old.responses {
+            let subject = format!("response {}", status);
+
+            match new.responses.iter().find(|(s, _)| s == status) {
+                None => self.push(
+                    ChangeKind::Breaking,
+                    &target,
+                    format!("Removed {}", subject),
+                ),
+                Some((_, new_type)) => match (old_type, new_type) {
+                    (Some(old_type), Some(new_type)) => {
+                        self.diff_type(&target, &subject, old_type, new_type)
+                    }
+                    (None, None) => {}
+                    (None, Some(new_type)) => self.push(
+                        ChangeKind::Breaking,
+                        &target,
+                        format!("Added body of type `{}` to {}", new_type, subject),
+                    ),
+                    (Some(_), None) => self.push(
+                        ChangeKind::Breaking,
+                        &target,
+                        format!("Removed body from {}", subject),
+                    ),
+                },
+            }
+        }
+
+        for (status, _) in &new.responses {
+            if !old.responses.iter().any(|(s, _)| s == status) {
+                self.push(
+                    ChangeKind::NonBreaking,
+                    &target,
+                    format!("Added response {}", status),
+                );
+            }
+        }
+    }
+}
+
+/// Compares two versions of a specification and classifies every change by
+/// whether it can break existing clients or only affects documentation.
+pub fn diff_schemas(old: &Schema, new: &Schema) -> Vec<Change> {
+    let mut differ = Differ {
+        changes: Vec::new(),
+    };
+
+    differ.diff_documentation(&ChangeTarget::Schema, "title", &old.title, &new.title);
+    differ.diff_documentation(
+        &ChangeTarget::Schema,
+        "description",
+        &old.description,
+        &new.description,
+    );
+
+    for old_object in &old.objects {
+        match new.get_object(&old_object.id) {
+            Some(new_object) => differ.diff_object(old_object, new_object),
+            None => differ.push(
+                ChangeKind::Breaking,
+                &ChangeTarget::Object(old_object.id.clone()),
+                "Removed object".to_string(),
+            ),
+        }
+    }
+    for new_object in &new.objects {
+        if old.get_object(&new_object.id).is_none() {
+            differ.push(
+                ChangeKind::NonBreaking,
+                &ChangeTarget::Object(new_object.id.clone()),
+                "Added object".to_string(),
+            );
+        }
+    }
+
+    for old_endpoint in &old.endpoints {
+        let key = endpoint_key(old_endpoint);
+
+        match new.endpoints.iter().find(|e| endpoint_key(e) == key) {
+            Some(new_endpoint) => differ.diff_endpoint(old_endpoint, new_endpoint),
+            None => differ.push(
+                ChangeKind::Breaking,
+                &ChangeTarget::Endpoint(key.0, key.1),
+                "Removed endpoint".to_string(),
+            ),
+        }
+    }
+    for new_endpoint in &new.endpoints {
+        let key = endpoint_key(new_endpoint);
+
+        if !old.endpoints.iter().any(|e| endpoint_key(e) == key) {
+            differ.push(
+                ChangeKind::NonBreaking,
+                &ChangeTarget::Endpoint(key.0, key.1),
+                "Added endpoint".to_string(),
+            );
+        }
+    }
+
+    differ.changes
+}
+
+fn format_text(changes: &[Change]) -> String {
+    if changes.is_empty() {
+        return "No changes.\n".to_string();
+    }
+
+    let mut output = String::new();
+    for change in changes {
+        output.push_str(&format!(
+            "{}: {}: {}\n",
+            change.kind.label(),
+            change.target.label(),
+            change.message
+        ));
+    }
+
+    let count = |kind| changes.iter().filter(|c| c.kind == kind).count();
+    output.push_str(&format!(
+        "\n{} breaking, {} non-breaking, {} documentation\n",
+        count(ChangeKind::Breaking),
+        count(ChangeKind::NonBreaking),
+        count(ChangeKind::Documentation)
+    ));
+    output
+}
+
+fn format_json(changes: &[Change]) -> String {
+    let changes = changes
+        .iter()
+        .map(|change| {
+            let mut entries = vec![(
+                "kind".to_string(),
+                JsonValue::String(change.kind.label().to_string()),
+            )];
+            match &change.target {
+                ChangeTarget::Schema => {}
+                ChangeTarget::Object(id) => {
+                    entries.push(("object".to_string(), JsonValue::String(id.clone())))
+                }
+                ChangeTarget::Endpoint(method, path) => entries.push((
+                    "endpoint".to_string(),
+                    JsonValue::String(format!("{} {}", method, path)),
+                )),
+            }
+            entries.push((
+                "message".to_string(),
+                JsonValue::String(change.message.clone()),
+            ));
+
+            JsonValue::Object(entries)
+        })
+        .collect();
+
+    format!("{}\n", JsonValue::Array(changes).to_pretty_json(0))
+}
+
+fn format_markdown(changes: &[Change]) -> String {
+    if changes.is_empty() {
+        return "No changes.\n".to_string();
+    }
+
+    let mut output = String::new();
+    for (kind, heading) in [
+        (ChangeKind::Breaking, "Breaking Changes"),
+        (ChangeKind::NonBreaking, "Non-breaking Changes"),
+        (ChangeKind::Documentation, "Documentation Changes"),
+    ] {
+        let matching: Vec<&Change> = changes.iter().filter(|c| c.kind == kind).collect();
+        if matching.is_empty() {
+            continue;
+        }
+
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(&format!("## {}\n\n", heading));
+        for change in matching {
+            output.push_str(&format!(
+                "- **{}**: {}\n",
+                change.target.label(),
+                change.message
+            ));
+        }
+    }
+    output
+}
+
+pub fn format_diff(changes: &[Change], format: DiffFormat) -> String {
+    match format {
+        DiffFormat::Text => format_text(changes),
+        DiffFormat::Json => format_json(changes),
+        DiffFormat::Markdown => format_markdown(changes),
+    }
+}
@@ -1,6 +1,96 @@
-use crate::{Schema, Type};
+use std::collections::HashMap;
 
-fn format_type(t: &Type) -> String {
+use crate::{
+    DiagramFormat, Endpoint, ExampleOptions, Field, JsonValue, Schema, Type, generate_diagram,
+    generate_example,
+};
+
+/// Converts a heading into the anchor GitHub generates for it, before
+/// de-duplication.
+pub(crate) fn heading_anchor(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Generates anchors for headings in document order, suffixing repeated
+/// anchors with `-1`, `-2` and so on as GitHub does.
+#[derive(Debug, Default)]
+struct Slugger {
+    occurrences: HashMap<String, usize>,
+}
+
+impl Slugger {
+    fn slug(&mut self, heading: &str) -> String {
+        let base = heading_anchor(heading);
+        let mut slug = base.clone();
+
+        while self.occurrences.contains_key(&slug) {
+            let count = self.occurrences.entry(base.clone()).or_default();
+            *count += 1;
+            slug = format!("{}-{}", base, count);
+        }
+
+        self.occurrences.insert(slug.clone(), 0);
+        slug
+    }
+}
+
+/// A heading that can be linked to from elsewhere in the documentation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Section {
+    Diagram,
+    Objects,
+    Endpoints,
+    Object(String),
+    Tag(String),
+    /// The index of an endpoint in the specification.
+    Endpoint(usize),
+}
+
+/// The anchors of every linkable heading, in order of appearance. Endpoints
+/// with several tags appear once per tag when grouped.
+#[derive(Debug, Default)]
+pub(crate) struct Anchors {
+    sections: HashMap<Section, Vec<String>>,
+}
+
+impl Anchors {
+    fn get(&self, section: &Section, occurrence: usize) -> Option<&str> {
+        self.sections
+            .get(section)
+            .and_then(|anchors| anchors.get(occurrence))
+            .map(|anchor| anchor.as_str())
+    }
+
+    pub(crate) fn object(&self, id: &str) -> Option<&str> {
+        self.get(&Section::Object(id.to_string()), 0)
+    }
+
+    pub(crate) fn endpoint(&self, index: usize) -> Option<&str> {
+        self.get(&Section::Endpoint(index), 0)
+    }
+}
+
+/// Escapes content so it stays within a single Markdown table cell, and so
+/// that text such as `<string, integer>` is not taken for an HTML tag.
+fn escape_cell(content: &str) -> String {
+    content
+        .replace("\r\n", "\n")
+        .replace('|', "\\|")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\n', "<br>")
+}
+
+fn format_type(t: &Type, anchors: &Anchors) -> String {
     match t {
         crate::Type::Any => "any".to_string(),
         crate::Type::Null => "null".to_string(),
@@ -8,120 +98,413 @@ fn format_type(t: &Type) -> String {
         crate::Type::Integer => "integer".to_string(),
         crate::Type::Float => "float".to_string(),
         crate::Type::Boolean => "boolean".to_string(),
-        crate::Type::Reference(name) => format!("[{}](#{})", name, name.to_lowercase()),
-        crate::Type::Array(item_type) => format!("[]{}", format_type(item_type)),
+        crate::Type::Reference(name) => {
+            let anchor = match anchors.object(name) {
+                Some(anchor) => anchor.to_string(),
+                None => heading_anchor(name),
+            };
+            format!("[{}](#{})", name, anchor)
+        }
+        crate::Type::Array(item_type) => format!("[]{}", format_type(item_type, anchors)),
         crate::Type::Mapping(key_type, value_type) => {
-            format!("<{}, {}>", format_type(key_type), format_type(value_type))
+            format!(
+                "&lt;{}, {}&gt;",
+                format_type(key_type, anchors),
+                format_type(value_type, anchors)
+            )
         }
-        crate::Type::Optional(inner_type) => format!("?{}", format_type(inner_type)),
+        crate::Type::Optional(inner_type) => format!("?{}", format_type(inner_type, anchors)),
     }
 }
 
-pub fn generate_docs(schema: &Schema) -> String {
-    let mut docs = String::new();
+fn format_example_block(example: &JsonValue) -> String {
+    format!("```json\n{}\n```\n\n", example.to_pretty_json(0))
+}
+
+fn format_field_table(name_header: &str, fields: &[Field], anchors: &Anchors) -> String {
+    let mut table = String::new();
+    let has_examples = fields.iter().any(|f| f.example.is_some());
+
+    if has_examples {
+        table.push_str(&format!(
+            "| {} | Type | Description | Example |\n",
+            name_header
+        ));
+        table.push_str(&format!(
+            "|{}|------|-------------|---------|\n",
+            "-".repeat(name_header.len() + 2)
+        ));
+    } else {
+        table.push_str(&format!("| {} | Type | Description |\n", name_header));
+        table.push_str(&format!(
+            "|{}|------|-------------|\n",
+            "-".repeat(name_header.len() + 2)
+        ));
+    }
 
-    if let Some(title) = &schema.title {
-        docs.push_str(&format!("# {}\n\n", title));
+    for field in fields {
+        table.push_str(&format!(
+            "| {} | {} | {} |",
+            escape_cell(&field.name),
+            format_type(&field.field_type, anchors),
+            escape_cell(&field.description)
+        ));
+        if has_examples {
+            match &field.example {
+                Some(example) => {
+                    table.push_str(&format!(" `{}` |", example.to_json().replace('|', "\\|")))
+                }
+                None => table.push_str("  |"),
+            }
+        }
+        table.push('\n');
     }
 
-    if let Some(version) = &schema.version {
-        docs.push_str(&format!("**Version:** {}\n\n", version));
+    table.push('\n');
+    table
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DocsOptions {
+    /// Lists endpoints under a heading per tag instead of in declaration order.
+    pub group_by_tag: bool,
+    /// Embeds a Mermaid class diagram of the objects.
+    pub diagram: bool,
+    /// Starts the document with a linked table of contents.
+    pub table_of_contents: bool,
+}
+
+/// Splits endpoints into groups by tag in order of first appearance, with
+/// untagged endpoints last under "Other". Endpoints with several tags are
+/// listed in each of their groups.
+fn group_endpoints(schema: &Schema) -> Vec<(String, Vec<usize>)> {
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut untagged = Vec::new();
+
+    for (index, endpoint) in schema.endpoints.iter().enumerate() {
+        if endpoint.tags.is_empty() {
+            untagged.push(index);
+        }
+        for tag in &endpoint.tags {
+            match groups.iter_mut().find(|(name, _)| name == tag) {
+                Some((_, endpoints)) => endpoints.push(index),
+                None => groups.push((tag.clone(), vec![index])),
+            }
+        }
     }
 
-    if let Some(description) = &schema.description {
-        docs.push_str(&format!("{}\n\n", description));
+    if !untagged.is_empty() {
+        groups.push(("Other".to_string(), untagged));
     }
+    groups
+}
 
-    docs.push_str("## Objects\n\n");
-    for object in &schema.objects {
-        docs.push_str(&format!("### {}\n\n", object.id));
+fn endpoint_heading(endpoint: &Endpoint) -> String {
+    format!("{} {}", endpoint.method.to_uppercase(), endpoint.path)
+}
 
-        docs.push_str("| Property | Type | Description |\n");
-        docs.push_str("|----------|------|-------------|\n");
-        for field in &object.fields {
-            docs.push_str(&format!(
-                "| {} | {} | {} |\n",
-                field.name,
-                format_type(&field.field_type),
-                field.description
-            ));
+fn references(t: &Type, id: &str) -> bool {
+    match t {
+        Type::Reference(name) => name == id,
+        Type::Array(inner) | Type::Optional(inner) => references(inner, id),
+        Type::Mapping(key, value) => references(key, id) || references(value, id),
+        _ => false,
+    }
+}
+
+fn endpoint_references(endpoint: &Endpoint, id: &str) -> bool {
+    endpoint
+        .params
+        .iter()
+        .chain(&endpoint.query)
+        .any(|f| references(&f.field_type, id))
+        || endpoint
+            .request_body
+            .as_ref()
+            .is_some_and(|t| references(t, id))
+        || endpoint
+            .responses
+            .iter()
+            .any(|(_, t)| t.as_ref().is_some_and(|t| references(t, id)))
+}
+
+/// Renders the documentation while recording the anchor of each heading.
+/// Links use the anchors recorded by a previous render, since they may point
+/// to headings further down the document.
+struct Renderer<'a> {
+    schema: &'a Schema,
+    options: &'a DocsOptions,
+    anchors: &'a Anchors,
+    found: Anchors,
+    slugger: Slugger,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(schema: &'a Schema, options: &'a DocsOptions, anchors: &'a Anchors) -> Self {
+        Renderer {
+            schema,
+            options,
+            anchors,
+            found: Anchors::default(),
+            slugger: Slugger::default(),
+        }
+    }
+
+    fn heading(&mut self, level: usize, text: &str, section: Option<Section>) -> String {
+        let anchor = self.slugger.slug(text);
+        if let Some(section) = section {
+            self.found.sections.entry(section).or_default().push(anchor);
         }
-        docs.push_str("\n");
+        format!("{} {}\n\n", "#".repeat(level), text)
     }
 
-    docs.push_str("## Endpoints\n\n");
-    for endpoint in &schema.endpoints {
-        docs.push_str(&format!(
-            "### {} {}\n\n",
-            endpoint.method.to_uppercase(),
-            endpoint.path
+    fn link(&self, text: &str, section: &Section, occurrence: usize) -> String {
+        let anchor = match self.anchors.get(section, occurrence) {
+            Some(anchor) => anchor.to_string(),
+            None => heading_anchor(text),
+        };
+        format!("[{}](#{})", text, anchor)
+    }
+
+    fn format_table_of_contents(&mut self) -> String {
+        let mut toc = self.heading(2, "Table of Contents", None);
+
+        if self.options.diagram {
+            toc.push_str(&format!(
+                "- {}\n",
+                self.link("Diagram", &Section::Diagram, 0)
+            ));
+        }
+
+        toc.push_str(&format!(
+            "- {}\n",
+            self.link("Objects", &Section::Objects, 0)
         ));
+        for object in &self.schema.objects {
+            let section = Section::Object(object.id.clone());
+            toc.push_str(&format!("  - {}\n", self.link(&object.id, &section, 0)));
+        }
+
+        toc.push_str(&format!(
+            "- {}\n",
+            self.link("Endpoints", &Section::Endpoints, 0)
+        ));
+        if self.options.group_by_tag {
+            let mut occurrences: HashMap<usize, usize> = HashMap::new();
+            for (tag, endpoints) in group_endpoints(self.schema) {
+                let section = Section::Tag(tag.clone());
+                toc.push_str(&format!("  - {}\n", self.link(&tag, &section, 0)));
+
+                for index in endpoints {
+                    let occurrence = occurrences.entry(index).or_default();
+                    let heading = endpoint_heading(&self.schema.endpoints[index]);
+                    toc.push_str(&format!(
+                        "    - {}\n",
+                        self.link(&heading, &Section::Endpoint(index), *occurrence)
+                    ));
+                    *occurrence += 1;
+                }
+            }
+        } else {
+            for (index, endpoint) in self.schema.endpoints.iter().enumerate() {
+                let heading = endpoint_heading(endpoint);
+                toc.push_str(&format!(
+                    "  - {}\n",
+                    self.link(&heading, &Section::Endpoint(index), 0)
+                ));
+            }
+        }
+
+        toc.push('\n');
+        toc
+    }
+
+    /// Lists the objects and endpoints that refer to an object.
+    fn format_used_by(&self, id: &str) -> String {
+        let mut links = Vec::new();
+
+        for object in &self.schema.objects {
+            if object.fields.iter().any(|f| references(&f.field_type, id)) {
+                let section = Section::Object(object.id.clone());
+                links.push(self.link(&object.id, &section, 0));
+            }
+        }
+        for (index, endpoint) in self.schema.endpoints.iter().enumerate() {
+            if endpoint_references(endpoint, id) {
+                let heading = endpoint_heading(endpoint);
+                links.push(self.link(&heading, &Section::Endpoint(index), 0));
+            }
+        }
+
+        if links.is_empty() {
+            return String::new();
+        }
+        format!("**Used by:** {}\n\n", links.join(", "))
+    }
+
+    /// Formats the type of a request body, the fields of the object it refers
+    /// to and a declared or generated example.
+    fn format_request_body(&self, endpoint: &Endpoint, body_type: &Type) -> String {
+        let mut docs = format!("**Type:** {}\n\n", format_type(body_type, self.anchors));
+
+        let inner_type = match body_type {
+            Type::Optional(inner_type) => {
+                docs.push_str("The request body is optional.\n\n");
+                inner_type.as_ref()
+            }
+            body_type => body_type,
+        };
+        if let Type::Reference(id) = inner_type
+            && let Some(object) = self.schema.get_object(id)
+        {
+            docs.push_str(&format_field_table(
+                "Property",
+                &object.fields,
+                self.anchors,
+            ));
+        }
+
+        let example = match &endpoint.request_body_example {
+            Some(example) => example.clone(),
+            None => generate_example(self.schema, body_type, &ExampleOptions::default()),
+        };
+        docs.push_str("**Example:**\n\n");
+        docs.push_str(&format_example_block(&example));
+
+        docs
+    }
+
+    /// Formats an endpoint with its heading at `level`, and its subsections
+    /// one level below.
+    fn format_endpoint(&mut self, index: usize, level: usize) -> String {
+        let endpoint = &self.schema.endpoints[index];
+        let mut docs = self.heading(
+            level,
+            &endpoint_heading(endpoint),
+            Some(Section::Endpoint(index)),
+        );
+
         if let Some(desc) = &endpoint.description {
             docs.push_str(&format!("{}\n\n", desc));
         }
 
+        if !endpoint.tags.is_empty() {
+            let tags: Vec<String> = endpoint.tags.iter().map(|t| format!("`{}`", t)).collect();
+            docs.push_str(&format!("**Tags:** {}\n\n", tags.join(", ")));
+        }
+
         if !endpoint.params.is_empty() {
-            docs.push_str("#### Parameters\n\n");
-            docs.push_str("| Name | Type | Description |\n");
-            docs.push_str("|------|------|-------------|\n");
-            for param in &endpoint.params {
-                docs.push_str(&format!(
-                    "| {} | {} | {} |\n",
-                    param.name,
-                    format_type(&param.field_type),
-                    param.description
-                ));
-            }
-            docs.push_str("\n");
+            docs.push_str(&self.heading(level + 1, "Parameters", None));
+            docs.push_str(&format_field_table("Name", &endpoint.params, self.anchors));
         }
 
         if !endpoint.query.is_empty() {
-            docs.push_str("#### Query Parameters\n\n");
-            docs.push_str("| Name | Type | Description |\n");
-            docs.push_str("|------|------|-------------|\n");
-            for query in &endpoint.query {
-                docs.push_str(&format!(
-                    "| {} | {} | {} |\n",
-                    query.name,
-                    format_type(&query.field_type),
-                    query.description
-                ));
-            }
-            docs.push_str("\n");
+            docs.push_str(&self.heading(level + 1, "Query Parameters", None));
+            docs.push_str(&format_field_table("Name", &endpoint.query, self.anchors));
         }
-        docs.push_str("#### Responses\n\n");
+
+        if let Some(body_type) = &endpoint.request_body {
+            docs.push_str(&self.heading(level + 1, "Request Body", None));
+            docs.push_str(&self.format_request_body(endpoint, body_type));
+        }
+
+        docs.push_str(&self.heading(level + 1, "Responses", None));
         docs.push_str("| Status Code | Type |\n");
         docs.push_str("|-------------|------|\n");
         for (status, resp_type) in &endpoint.responses {
             let type_str = match resp_type {
-                Some(t) => format_type(t),
+                Some(t) => format_type(t, self.anchors),
                 None => "No Content".to_string(),
             };
             docs.push_str(&format!("| {} | {} |\n", status, type_str));
         }
-        docs.push_str("\n");
+        docs.push('\n');
+
+        for (status, example) in &endpoint.response_examples {
+            let heading = format!("Example {} Response", status);
+            docs.push_str(&self.heading(level + 1, &heading, None));
+            docs.push_str(&format_example_block(example));
+        }
+
+        docs
     }
-    docs
-}
 
-pub fn generate_summary(schema: &Schema) -> String {
-    let mut summary = String::new();
+    fn render(&mut self) -> String {
+        let schema = self.schema;
+        let mut docs = String::new();
 
-    summary.push_str("# Summary\n\n## Objects\n\n");
+        if let Some(title) = &schema.title {
+            docs.push_str(&self.heading(1, title, None));
+        }
 
-    for object in &schema.objects {
-        summary.push_str(&format!("- `{}`\n", object.id));
-    }
+        if let Some(version) = &schema.version {
+            docs.push_str(&format!("**Version:** {}\n\n", version));
+        }
 
-    summary.push_str("\n## Endpoints\n\n");
+        if let Some(description) = &schema.description {
+            docs.push_str(&format!("{}\n\n", description));
+        }
 
-    for endpoint in &schema.endpoints {
-        summary.push_str(&format!(
-            "- `{} {}`\n",
-            endpoint.method.to_uppercase(),
-            endpoint.path,
-        ));
+        if self.options.table_of_contents {
+            docs.push_str(&self.format_table_of_contents());
+        }
+
+        if self.options.diagram {
+            docs.push_str(&self.heading(2, "Diagram", Some(Section::Diagram)));
+            docs.push_str(&format!(
+                "```mermaid\n{}```\n\n",
+                generate_diagram(schema, DiagramFormat::Mermaid)
+            ));
+        }
+
+        docs.push_str(&self.heading(2, "Objects", Some(Section::Objects)));
+        for object in &schema.objects {
+            docs.push_str(&self.heading(3, &object.id, Some(Section::Object(object.id.clone()))));
+
+            docs.push_str(&format_field_table(
+                "Property",
+                &object.fields,
+                self.anchors,
+            ));
+            docs.push_str(&self.format_used_by(&object.id));
+
+            if let Some(example) = &object.example {
+                docs.push_str(&self.heading(4, "Example", None));
+                docs.push_str(&format_example_block(example));
+            }
+        }
+
+        docs.push_str(&self.heading(2, "Endpoints", Some(Section::Endpoints)));
+        if self.options.group_by_tag {
+            for (tag, endpoints) in group_endpoints(schema) {
+                docs.push_str(&self.heading(3, &tag, Some(Section::Tag(tag.clone()))));
+                for index in endpoints {
+                    docs.push_str(&self.format_endpoint(index, 4));
+                }
+            }
+        } else {
+            for index in 0..schema.endpoints.len() {
+                docs.push_str(&self.format_endpoint(index, 3));
+            }
+        }
+
+        docs
     }
+}
+
+/// Finds the anchor of every linkable heading in the documentation.
+pub(crate) fn docs_anchors(schema: &Schema, options: &DocsOptions) -> Anchors {
+    let empty = Anchors::default();
+    let mut renderer = Renderer::new(schema, options, &empty);
+    renderer.render();
+    renderer.found
+}
+
+pub fn generate_docs(schema: &Schema) -> String {
+    generate_docs_with_options(schema, &DocsOptions::default())
+}
 
-    summary
+pub fn generate_docs_with_options(schema: &Schema, options: &DocsOptions) -> String {
+    let anchors = docs_anchors(schema, options);
+    Renderer::new(schema, options, &anchors).render()
 }
@@ -0,0 +1,194 @@
+use crate::{JsonValue, Schema, Type};
+
+const WORDS: &[&str] = &[
+    "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india", "juliet",
+    "kilo", "lima", "mike", "november", "oscar", "papa",
+];
+
+const NAMES: &[&str] = &[
+    "Alice", "Bob", "Carys", "Dafydd", "Elin", "Gareth", "Heledd", "Iwan", "Megan", "Rhys",
+];
+
+#[derive(Debug, Clone)]
+pub struct ExampleOptions {
+    pub seed: u64,
+    pub array_length: usize,
+    pub max_depth: usize,
+    pub include_optional: bool,
+}
+
+impl Default for ExampleOptions {
+    fn default() -> Self {
+        ExampleOptions {
+            seed: 0,
+            array_length: 2,
+            max_depth: 4,
+            include_optional: false,
+        }
+    }
+}
+
+struct Generator<'a> {
+    schema: &'a Schema,
+    options: &'a ExampleOptions,
+    state: u64,
+    stack: Vec<String>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(schema: &'a Schema, options: &'a ExampleOptions) -> Self {
+        Generator {
+            schema,
+            options,
+            state: options.seed,
+            stack: Vec::new(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        // splitmix64, so that examples are stable for a given seed.
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_range(&mut self, max: u64) -> u64 {
+        self.next_u64() % max
+    }
+
+    fn pick<'b>(&mut self, items: &[&'b str]) -> &'b str {
+        items[self.next_range(items.len() as u64) as usize]
+    }
+
+    fn string_for(&mut self, name: Option<&str>) -> String {
+        let name = name.unwrap_or("").to_lowercase();
+
+        if name.contains("email") {
+            format!("{}@example.com", self.pick(NAMES).to_lowercase())
+        } else if name.ends_with("_at") || name.contains("date") || name.contains("time") {
+            format!(
+                "2024-{:02}-{:02}T{:02}:{:02}:00Z",
+                self.next_range(12) + 1,
+                self.next_range(28) + 1,
+                self.next_range(24),
+                self.next_range(60)
+            )
+        } else if name.contains("url") || name.contains("link") {
+            format!("https://example.com/{}", self.pick(WORDS))
+        } else if name == "id" || name.ends_with("_id") {
+            format!("{:016x}", self.next_u64())
+        } else if name.contains("name") {
+            self.pick(NAMES).to_string()
+        } else if name.contains("token") || name.contains("password") || name.contains("secret") {
+            format!("{:016x}{:016x}", self.next_u64(), self.next_u64())
+        } else {
+            format!("{} {}", self.pick(WORDS), self.pick(WORDS))
+        }
+    }
+
+    fn key_for(&mut self, key_type: &Type) -> String {
+        match key_type {
+            Type::Integer => self.next_range(1000).to_string(),
+            Type::Float => format!("{:.2}", self.next_range(100_000) as f64 / 100.0),
+            Type::Boolean => (self.next_range(2) == 0).to_string(),
+            Type::Optional(inner) => self.key_for(inner),
+            _ => self.pick(WORDS).to_string(),
+        }
+    }
+
+    fn generate(&mut self, t: &Type, name: Option<&str>) -> JsonValue {
+        match t {
+            Type::Any => JsonValue::String(self.pick(WORDS).to_string()),
+            Type::Null => JsonValue::Null,
+            Type::String => JsonValue::String(self.string_for(name)),
+            Type::Integer => JsonValue::Integer(self.next_range(1000) as i64),
+            Type::Float => JsonValue::Float(self.next_range(100_000) as f64 / 100.0),
+            Type::Boolean => JsonValue::Boolean(self.next_range(2) == 0),
+            Type::Array(item_type) => {
+                let length = if self.stack.len() >= self.options.max_depth {
+                    0
+                } else {
+                    self.options.array_length
+                };
+
+                JsonValue::Array(
+                    (0..length)
+                        .map(|_| self.generate(item_type, name))
+                        .collect(),
+                )
+            }
+            Type::Mapping(key_type, value_type) => {
+                let length = if self.stack.len() >= self.options.max_depth {
+                    0
+                } else {
+                    self.options.array_length
+                };
+
+                let mut entries: Vec<(String, JsonValue)> = Vec::new();
+                for _ in 0..length {
+                    let key = self.key_for(key_type);
+                    let value = self.generate(value_type, name);
+                    if !entries.iter().any(|(k, _)| k == &key) {
+                        entries.push((key, value));
+                    }
+                }
+
+                JsonValue::Object(entries)
+            }
+            Type::Optional(inner_type) => {
+                if self.stack.len() >= self.options.max_depth {
+                    JsonValue::Null
+                } else {
+                    self.generate(inner_type, name)
+                }
+            }
+            Type::Reference(ref_name) => self.generate_object(ref_name),
+        }
+    }
+
+    fn generate_object(&mut self, id: &str) -> JsonValue {
+        let Some(object) = self.schema.get_object(id) else {
+            return JsonValue::Null;
+        };
+
+        if let Some(example) = &object.example {
+            return example.clone();
+        }
+
+        // Past `max_depth` only required fields are generated, so reaching an
+        // object again means it always contains itself and has no finite
+        // value. `Schema::verify` rejects such specifications.
+        if self.stack.len() > self.options.max_depth
+            && self.stack[self.options.max_depth..].iter().any(|s| s == id)
+        {
+            return JsonValue::Null;
+        }
+
+        self.stack.push(id.to_string());
+
+        let mut entries = Vec::new();
+        for field in &object.fields {
+            if let Type::Optional(_) = field.field_type {
+                let omit = !self.options.include_optional && self.next_range(2) == 0;
+                if omit || self.stack.len() >= self.options.max_depth {
+                    continue;
+                }
+            }
+
+            let value = match &field.example {
+                Some(example) => example.clone(),
+                None => self.generate(&field.field_type, Some(&field.name)),
+            };
+            entries.push((field.name.clone(), value));
+        }
+
+        self.stack.pop();
+        JsonValue::Object(entries)
+    }
+}
+
+pub fn generate_example(schema: &Schema, t: &Type, options: &ExampleOptions) -> JsonValue {
+    Generator::new(schema, options).generate(t, None)
+}
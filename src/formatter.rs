@@ -1,10 +1,4 @@
-use crate::{
-    Schema,
-    Object,
-    Endpoint,
-    Field,
-    Type,
-};
+use crate::{Endpoint, Field, JsonValue, Object, Schema, Type};
 
 fn escape_string(s: &str) -> String {
     let mut result = String::new();
@@ -28,6 +22,13 @@ fn escape_string(s: &str) -> String {
     result
 }
 
+fn format_example(example: &Option<JsonValue>) -> String {
+    match example {
+        Some(value) => format!(" example {}", value.to_json()),
+        None => String::new(),
+    }
+}
+
 trait Formatable {
     fn format(&self) -> String;
 }
@@ -55,6 +56,12 @@ impl Formatable for Type {
     }
 }
 
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Formatable::format(self))
+    }
+}
+
 impl FormatableField for Field {
     fn format(&self) -> (String, String, String) {
         (
@@ -85,15 +92,20 @@ impl Formatable for Object {
         for field in &self.fields {
             let (name, field_type, description) = field.format();
             result.push_str(&format!(
-                "    prop {:width_name$} {:width_type$} \"{}\"\n",
+                "    prop {:width_name$} {:width_type$} \"{}\"{}\n",
                 name,
                 field_type,
                 description,
+                format_example(&field.example),
                 width_name = longest_name,
                 width_type = longest_type
             ));
         }
 
+        if let Some(example) = &self.example {
+            result.push_str(&format!("\n    example {}\n", example.to_pretty_json(1)));
+        }
+
         result.push_str("}\n");
         result
     }
@@ -101,61 +113,87 @@ impl Formatable for Object {
 
 impl Formatable for Endpoint {
     fn format(&self) -> String {
-        let mut result = format!("route {} \"{}\" {{\n", self.method, escape_string(&self.path));
+        let mut result = format!(
+            "route {} \"{}\" {{\n",
+            self.method,
+            escape_string(&self.path)
+        );
 
         if let Some(description) = &self.description {
-            result.push_str(&format!("    description \"{}\"\n", escape_string(description)));
+            result.push_str(&format!(
+                "    description \"{}\"\n",
+                escape_string(description)
+            ));
             result.push('\n');
         }
 
         for tag in &self.tags {
             result.push_str(&format!("    tag \"{}\"\n", escape_string(tag)));
         }
-        if self.tags.len() > 0 {
+        if !self.tags.is_empty() {
             result.push('\n');
         }
 
         for param in &self.params {
             let (name, field_type, description) = param.format();
             result.push_str(&format!(
-                "    param {} {} \"{}\"\n",
+                "    param {} {} \"{}\"{}\n",
                 name,
                 field_type,
-                escape_string(&description)
+                escape_string(&description),
+                format_example(&param.example)
             ));
         }
-        if self.params.len() > 0 {
+        if !self.params.is_empty() {
             result.push('\n');
         }
 
         for query in &self.query {
             let (name, field_type, description) = query.format();
             result.push_str(&format!(
-                "    query {} {} \"{}\"\n",
+                "    query {} {} \"{}\"{}\n",
                 name,
                 field_type,
-                escape_string(&description)
+                escape_string(&description),
+                format_example(&query.example)
             ));
         }
-        if self.query.len() > 0 {
+        if !self.query.is_empty() {
             result.push('\n');
         }
 
         if let Some(body_type) = &self.request_body {
-            result.push_str(&format!(
-                "    body {}\n",
-                body_type.format()
-            ));
+            match &self.request_body_example {
+                Some(example) => result.push_str(&format!(
+                    "    body {} example {}\n",
+                    body_type.format(),
+                    example.to_pretty_json(1)
+                )),
+                None => result.push_str(&format!("    body {}\n", body_type.format())),
+            }
             result.push('\n');
         }
 
         for (status_code, response_type) in &self.responses {
             if let Some(resp_type) = response_type {
-                result.push_str(&format!(
-                    "    response {} {}\n",
-                    status_code,
-                    resp_type.format()
-                ));
+                let example = self
+                    .response_examples
+                    .iter()
+                    .find(|(code, _)| code == status_code);
+
+                match example {
+                    Some((_, example)) => result.push_str(&format!(
+                        "    response {} {} example {}\n",
+                        status_code,
+                        resp_type.format(),
+                        example.to_pretty_json(1)
+                    )),
+                    None => result.push_str(&format!(
+                        "    response {} {}\n",
+                        status_code,
+                        resp_type.format()
+                    )),
+                }
             } else {
                 result.push_str(&format!("    response {}\n", status_code));
             }
@@ -207,4 +245,4 @@ impl Formatable for Schema {
 
 pub fn format(schema: &Schema) -> String {
     schema.format()
-}
\ No newline at end of file
+}
@@ -0,0 +1,549 @@
+use crate::{
+    Endpoint, Field, Schema, Type,
+    generators::{boxed_fields, endpoint_names, pascal_case, path_param_name, unique_idents},
+};
+
+const INITIALISMS: &[&str] = &[
+    "api", "html", "http", "https", "id", "ip", "json", "sql", "uri", "url", "uuid", "xml",
+];
+
+/// Converts a name into an exported Go identifier, e.g. `user_id` becomes `UserID`.
+fn go_ident(name: &str) -> String {
+    let mut ident = pascal_case(name);
+
+    for initialism in INITIALISMS {
+        let word = pascal_case(initialism);
+        let mut result = String::new();
+        let mut rest = ident.as_str();
+
+        while let Some(index) = rest.find(&word) {
+            let end = index + word.len();
+            let at_boundary = rest[end..]
+                .chars()
+                .next()
+                .is_none_or(|c| c.is_uppercase() || c.is_ascii_digit());
+
+            result.push_str(&rest[..index]);
+            if at_boundary {
+                result.push_str(&initialism.to_uppercase());
+            } else {
+                result.push_str(&word);
+            }
+            rest = &rest[end..];
+        }
+
+        result.push_str(rest);
+        ident = result;
+    }
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, 'X');
+    }
+
+    ident
+}
+
+fn format_comment(name: &str, description: &str, indent: &str) -> String {
+    if description.is_empty() {
+        return String::new();
+    }
+
+    description
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = if i == 0 {
+                format!("{} {}", name, line)
+            } else {
+                line.to_string()
+            };
+            if line.is_empty() {
+                format!("{}//\n", indent)
+            } else {
+                format!("{}// {}\n", indent, line)
+            }
+        })
+        .collect()
+}
+
+fn format_key_type(t: &Type) -> String {
+    match t {
+        Type::Integer => "int64".to_string(),
+        Type::Optional(inner) => format_key_type(inner),
+        _ => "string".to_string(),
+    }
+}
+
+fn format_type(t: &Type) -> String {
+    match t {
+        Type::Any | Type::Null => "any".to_string(),
+        Type::String => "string".to_string(),
+        Type::Integer => "int64".to_string(),
+        Type::Float => "float64".to_string(),
+        Type::Boolean => "bool".to_string(),
+        Type::Reference(name) => name.clone(),
+        Type::Array(item_type) => format!("[]{}", format_type(item_type)),
+        Type::Mapping(key_type, value_type) => {
+            format!(
+                "map[{}]{}",
+                format_key_type(key_type),
+                format_type(value_type)
+            )
+        }
+        Type::Optional(inner_type) => match inner_type.as_ref() {
+            Type::Any | Type::Null | Type::Array(_) | Type::Mapping(_, _) => {
+                format_type(inner_type)
+            }
+            _ => format!("*{}", format_type(inner_type)),
+        },
+    }
+}
+
+fn format_struct(name: &str, fields: &[Field], pointer: &dyn Fn(&Field) -> bool) -> String {
+    let idents = unique_idents(fields.iter().map(|f| f.name.as_str()), go_ident);
+    let rows: Vec<(String, String, String)> = fields
+        .iter()
+        .zip(idents)
+        .map(|(field, ident)| {
+            let mut field_type = format_type(&field.field_type);
+            if pointer(field) && !field_type.starts_with('*') {
+                field_type.insert(0, '*');
+            }
+
+            let tag = if matches!(field.field_type, Type::Optional(_)) {
+                format!("`json:\"{},omitempty\"`", field.name)
+            } else {
+                format!("`json:\"{}\"`", field.name)
+            };
+
+            (ident, field_type, tag)
+        })
+        .collect();
+
+    let name_width = rows.iter().map(|(n, _, _)| n.len()).max().unwrap_or(0);
+    let type_width = rows.iter().map(|(_, t, _)| t.len()).max().unwrap_or(0);
+
+    let mut output = format!("type {} struct {{\n", name);
+    for (field, (ident, field_type, tag)) in fields.iter().zip(rows.iter()) {
+        output.push_str(&format_comment(ident, &field.description, "\t"));
+        output.push_str(&format!(
+            "\t{:name_width$} {:type_width$} {}\n",
+            ident,
+            field_type,
+            tag,
+            name_width = name_width,
+            type_width = type_width
+        ));
+    }
+    output.push_str("}\n");
+
+    output
+}
+
+fn generate_header(schema: &Schema, imports: &[&str]) -> String {
+    let mut output = String::from("// Code generated by manyleb. DO NOT EDIT.\n");
+    if let Some(title) = &schema.title {
+        output.push_str(&format!("// {}", title));
+        if let Some(version) = &schema.version {
+            output.push_str(&format!(" ({})", version));
+        }
+        output.push('\n');
+    }
+    output.push_str("\npackage api\n");
+
+    match imports {
+        [] => {}
+        [import] => output.push_str(&format!("\nimport \"{}\"\n", import)),
+        imports => {
+            output.push_str("\nimport (\n");
+            for import in imports {
+                output.push_str(&format!("\t\"{}\"\n", import));
+            }
+            output.push_str(")\n");
+        }
+    }
+
+    output
+}
+
+fn generate_structs(schema: &Schema) -> String {
+    let pointers = boxed_fields(schema);
+    let mut output = String::new();
+
+    for object in &schema.objects {
+        output.push('\n');
+        output.push_str(&format_struct(&object.id, &object.fields, &|field| {
+            pointers.contains(&(object.id.clone(), field.name.clone()))
+        }));
+    }
+
+    output
+}
+
+pub fn generate_go(schema: &Schema) -> String {
+    let mut output = generate_header(schema, &[]);
+    output.push_str(&generate_structs(schema));
+    output
+}
+
+const CLIENT_RUNTIME: &str = r#"
+// Client calls the API over net/http.
+type Client struct {
+	BaseURL    string
+	HTTPClient *http.Client
+	Header     http.Header
+}
+
+// NewClient creates a client for the API served at baseURL.
+func NewClient(baseURL string) *Client {
+	return &Client{BaseURL: strings.TrimRight(baseURL, "/"), HTTPClient: http.DefaultClient}
+}
+
+// UnexpectedStatusError is returned when the API responds with a status code
+// that is not declared for the endpoint.
+type UnexpectedStatusError struct {
+	StatusCode int
+	Body       []byte
+}
+
+func (e *UnexpectedStatusError) Error() string {
+	return fmt.Sprintf("unexpected response status %d", e.StatusCode)
+}
+
+func (c *Client) do(ctx context.Context, method, path string, query url.Values, body any) (int, []byte, error) {
+	target := c.BaseURL + path
+	if len(query) > 0 {
+		target += "?" + query.Encode()
+	}
+
+	var reader io.Reader
+	if body != nil {
+		encoded, err := json.Marshal(body)
+		if err != nil {
+			return 0, nil, err
+		}
+		reader = bytes.NewReader(encoded)
+	}
+
+	request, err := http.NewRequestWithContext(ctx, method, target, reader)
+	if err != nil {
+		return 0, nil, err
+	}
+	for key, values := range c.Header {
+		request.Header[key] = values
+	}
+	request.Header.Set("Accept", "application/json")
+	if body != nil {
+		request.Header.Set("Content-Type", "application/json")
+	}
+
+	response, err := c.HTTPClient.Do(request)
+	if err != nil {
+		return 0, nil, err
+	}
+	defer response.Body.Close()
+
+	data, err := io.ReadAll(response.Body)
+	if err != nil {
+		return 0, nil, err
+	}
+
+	return response.StatusCode, data, nil
+}
+"#;
+
+fn success_responses(endpoint: &Endpoint) -> Vec<&(u16, Option<Type>)> {
+    endpoint
+        .responses
+        .iter()
+        .filter(|(status, _)| (200..300).contains(status))
+        .collect()
+}
+
+fn format_path(endpoint: &Endpoint) -> String {
+    let mut arguments = Vec::new();
+    let idents = unique_idents(endpoint.params.iter().map(|p| p.name.as_str()), go_ident);
+
+    let template = endpoint
+        .path
+        .split('/')
+        .map(|segment| {
+            let param = path_param_name(segment)
+                .and_then(|name| endpoint.params.iter().position(|p| p.name == name));
+
+            match param {
+                Some(index) => {
+                    arguments.push(format!(
+                        "url.PathEscape(fmt.Sprint(params.{}))",
+                        idents[index]
+                    ));
+                    "%s".to_string()
+                }
+                None => segment.replace('%', "%%"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    if arguments.is_empty() {
+        format!("{:?}", template.replace("%%", "%"))
+    } else {
+        format!("fmt.Sprintf({:?}, {})", template, arguments.join(", "))
+    }
+}
+
+fn generate_endpoint(endpoint: &Endpoint, name: &str) -> String {
+    let name = go_ident(name);
+    let mut output = String::new();
+
+    if !endpoint.params.is_empty() {
+        output.push_str(&format!(
+            "\n// {}Params holds the path parameters for {} {}.\n",
+            name,
+            endpoint.method.to_uppercase(),
+            endpoint.path
+        ));
+        output.push_str(&format_struct(
+            &format!("{}Params", name),
+            &endpoint.params,
+            &|_| false,
+        ));
+    }
+
+    if !endpoint.query.is_empty() {
+        output.push_str(&format!(
+            "\n// {}Query holds the query parameters for {} {}.\n",
+            name,
+            endpoint.method.to_uppercase(),
+            endpoint.path
+        ));
+        output.push_str(&format_struct(
+            &format!("{}Query", name),
+            &endpoint.query,
+            &|_| false,
+        ));
+    }
+
+    let successes = success_responses(endpoint);
+    if !successes.is_empty() {
+        output.push_str(&format!(
+            "\n// {}Response holds a successful response to {} {}.\n",
+            name,
+            endpoint.method.to_uppercase(),
+            endpoint.path
+        ));
+        let mut rows = vec![("StatusCode".to_string(), "int".to_string())];
+        for (status, response_type) in &successes {
+            if let Some(t) = response_type {
+                rows.push((
+                    format!("Status{}", status),
+                    format!("*{}", format_type(t).trim_start_matches('*')),
+                ));
+            }
+        }
+        rows.push(("Body".to_string(), "[]byte".to_string()));
+
+        let width = rows.iter().map(|(n, _)| n.len()).max().unwrap_or(0);
+        output.push_str(&format!("type {}Response struct {{\n", name));
+        for (field, field_type) in &rows {
+            let comment = match field.as_str() {
+                "StatusCode" => "StatusCode is the 2xx status the API responded with.".to_string(),
+                "Body" => "Body is the raw response body.".to_string(),
+                field => format!(
+                    "{} is the decoded body of a {} response, and nil otherwise.",
+                    field,
+                    field.trim_start_matches("Status")
+                ),
+            };
+            output.push_str(&format!("\t// {}\n", comment));
+            output.push_str(&format!("\t{:width$} {}\n", field, field_type));
+        }
+        output.push_str("}\n");
+    }
+
+    for (status, response_type) in &endpoint.responses {
+        if (200..300).contains(status) {
+            continue;
+        }
+
+        let error_name = format!("{}Status{}Error", name, status);
+        output.push_str(&format!(
+            "\n// {} is returned when {} {} responds with status {}.\n",
+            error_name,
+            endpoint.method.to_uppercase(),
+            endpoint.path,
+            status
+        ));
+        match response_type {
+            Some(t) => output.push_str(&format!(
+                "type {} struct {{\n\tBody {}\n}}\n",
+                error_name,
+                format_type(t)
+            )),
+            None => output.push_str(&format!("type {} struct{{}}\n", error_name)),
+        }
+        output.push_str(&format!(
+            "\nfunc (e *{}) Error() string {{\n\treturn \"{} {}: status {}\"\n}}\n",
+            error_name,
+            endpoint.method.to_uppercase(),
+            endpoint.path.escape_default(),
+            status
+        ));
+    }
+
+    let mut arguments = vec!["ctx context.Context".to_string()];
+    if !endpoint.params.is_empty() {
+        arguments.push(format!("params {}Params", name));
+    }
+    if !endpoint.query.is_empty() {
+        arguments.push(format!("query {}Query", name));
+    }
+    if let Some(body_type) = &endpoint.request_body {
+        arguments.push(format!("body {}", format_type(body_type)));
+    }
+
+    let returns = if successes.is_empty() {
+        "error".to_string()
+    } else {
+        format!("(*{}Response, error)", name)
+    };
+    let fail = if successes.is_empty() { "" } else { "nil, " };
+
+    output.push('\n');
+    let mut doc = format!(
+        "{} calls {} {}.",
+        name,
+        endpoint.method.to_uppercase(),
+        endpoint.path
+    );
+    if let Some(description) = &endpoint.description {
+        doc.push_str(&format!("\n\n{}", description));
+    }
+    for line in doc.lines() {
+        if line.is_empty() {
+            output.push_str("//\n");
+        } else {
+            output.push_str(&format!("// {}\n", line));
+        }
+    }
+    output.push_str(&format!(
+        "func (c *Client) {}({}) {} {{\n",
+        name,
+        arguments.join(", "),
+        returns
+    ));
+
+    if endpoint.query.is_empty() {
+        output.push_str("\tvar values url.Values\n");
+    } else {
+        output.push_str("\tvalues := url.Values{}\n");
+        let idents = unique_idents(endpoint.query.iter().map(|q| q.name.as_str()), go_ident);
+        for (query, ident) in endpoint.query.iter().zip(idents) {
+            if format_type(&query.field_type).starts_with('*') {
+                output.push_str(&format!(
+                    "\tif query.{} != nil {{\n\t\tvalues.Set(\"{}\", fmt.Sprint(*query.{}))\n\t}}\n",
+                    ident, query.name, ident
+                ));
+            } else {
+                output.push_str(&format!(
+                    "\tvalues.Set(\"{}\", fmt.Sprint(query.{}))\n",
+                    query.name, ident
+                ));
+            }
+        }
+    }
+
+    // A nil pointer stored in an `any` is not itself nil, so it would be sent
+    // as a `null` body.
+    let payload = match &endpoint.request_body {
+        Some(t) if format_type(t).starts_with('*') => {
+            output.push_str("\n\tvar payload any\n\tif body != nil {\n\t\tpayload = body\n\t}\n");
+            "payload"
+        }
+        Some(_) => "body",
+        None => "nil",
+    };
+
+    output.push_str(&format!(
+        "\n\tstatus, data, err := c.do(ctx, \"{}\", {}, values, {})\n",
+        endpoint.method.to_uppercase(),
+        format_path(endpoint),
+        payload
+    ));
+    output.push_str(&format!(
+        "\tif err != nil {{\n\t\treturn {}err\n\t}}\n\n",
+        fail
+    ));
+
+    output.push_str("\tswitch status {\n");
+    for (status, response_type) in &successes {
+        output.push_str(&format!("\tcase {}:\n", status));
+        output.push_str(&format!(
+            "\t\tresult := &{}Response{{StatusCode: status, Body: data}}\n",
+            name
+        ));
+        if response_type.is_some() {
+            output.push_str(&format!(
+                "\t\tif len(data) > 0 {{\n\t\t\tif err := json.Unmarshal(data, &result.Status{}); err != nil {{\n\t\t\t\treturn nil, err\n\t\t\t}}\n\t\t}}\n",
+                status
+            ));
+        }
+        output.push_str("\t\treturn result, nil\n");
+    }
+    for (status, response_type) in &endpoint.responses {
+        if (200..300).contains(status) {
+            continue;
+        }
+
+        let error_name = format!("{}Status{}Error", name, status);
+        output.push_str(&format!("\tcase {}:\n", status));
+        match response_type {
+            Some(_) => {
+                output.push_str(&format!("\t\tvar failure {}\n", error_name));
+                output.push_str(&format!(
+                    "\t\tif err := json.Unmarshal(data, &failure.Body); err != nil {{\n\t\t\treturn {}err\n\t\t}}\n",
+                    fail
+                ));
+                output.push_str(&format!("\t\treturn {}&failure\n", fail));
+            }
+            None => output.push_str(&format!("\t\treturn {}&{}{{}}\n", fail, error_name)),
+        }
+    }
+    output.push_str("\tdefault:\n");
+    output.push_str(&format!(
+        "\t\treturn {}&UnexpectedStatusError{{StatusCode: status, Body: data}}\n",
+        fail
+    ));
+    output.push_str("\t}\n");
+    output.push_str("}\n");
+
+    output
+}
+
+pub fn generate_go_client(schema: &Schema) -> String {
+    let mut output = generate_header(
+        schema,
+        &[
+            "bytes",
+            "context",
+            "encoding/json",
+            "fmt",
+            "io",
+            "net/http",
+            "net/url",
+            "strings",
+        ],
+    );
+    output.push_str(&generate_structs(schema));
+    output.push_str(CLIENT_RUNTIME);
+
+    for (endpoint, name) in schema
+        .endpoints
+        .iter()
+        .zip(endpoint_names(&schema.endpoints))
+    {
+        output.push_str(&generate_endpoint(endpoint, &name));
+    }
+
+    output
+}
@@ -0,0 +1,147 @@
+use crate::{
+    Schema, Type,
+    generators::{backtick_ident, typescript::format_jsdoc, unique_idents},
+};
+
+const KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+fn field_ident(name: &str) -> String {
+    backtick_ident(name, KEYWORDS)
+}
+
+fn format_key_type(t: &Type) -> String {
+    match t {
+        Type::Integer => "Long".to_string(),
+        Type::Float => "Double".to_string(),
+        Type::Boolean => "Boolean".to_string(),
+        Type::Optional(inner) => format_key_type(inner),
+        _ => "String".to_string(),
+    }
+}
+
+fn format_type(t: &Type) -> String {
+    match t {
+        Type::Any => "JsonElement".to_string(),
+        Type::Null => "JsonNull?".to_string(),
+        Type::String => "String".to_string(),
+        Type::Integer => "Long".to_string(),
+        Type::Float => "Double".to_string(),
+        Type::Boolean => "Boolean".to_string(),
+        Type::Reference(name) => name.clone(),
+        Type::Array(item_type) => format!("List<{}>", format_type(item_type)),
+        Type::Mapping(key_type, value_type) => format!(
+            "Map<{}, {}>",
+            format_key_type(key_type),
+            format_type(value_type)
+        ),
+        Type::Optional(inner_type) => match inner_type.as_ref() {
+            Type::Null | Type::Optional(_) => format_type(inner_type),
+            inner_type => format!("{}?", format_type(inner_type)),
+        },
+    }
+}
+
+fn type_contains(t: &Type, predicate: &dyn Fn(&Type) -> bool) -> bool {
+    predicate(t)
+        || match t {
+            Type::Array(inner) | Type::Optional(inner) => type_contains(inner, predicate),
+            Type::Mapping(key, value) => {
+                type_contains(key, predicate) || type_contains(value, predicate)
+            }
+            _ => false,
+        }
+}
+
+pub fn generate_kotlin(schema: &Schema) -> String {
+    let mut output = String::from("// Generated by manyleb. Do not edit by hand.\n");
+    if let Some(title) = &schema.title {
+        output.push_str(&format!("// {}", title));
+        if let Some(version) = &schema.version {
+            output.push_str(&format!(" ({})", version));
+        }
+        output.push('\n');
+    }
+    output.push_str("\npackage api\n\n");
+
+    let fields = || schema.objects.iter().flat_map(|o| o.fields.iter());
+    let uses = |predicate: &dyn Fn(&Type) -> bool| {
+        fields().any(|f| type_contains(&f.field_type, predicate))
+    };
+
+    if fields().any(|f| field_ident(&f.name).trim_matches('`') != f.name) {
+        output.push_str("import kotlinx.serialization.SerialName\n");
+    }
+    output.push_str("import kotlinx.serialization.Serializable\n");
+    if uses(&|t| matches!(t, Type::Any)) {
+        output.push_str("import kotlinx.serialization.json.JsonElement\n");
+    }
+    if uses(&|t| matches!(t, Type::Null)) {
+        output.push_str("import kotlinx.serialization.json.JsonNull\n");
+    }
+
+    for object in &schema.objects {
+        output.push_str("\n@Serializable\n");
+
+        // Data classes need at least one property.
+        if object.fields.is_empty() {
+            output.push_str(&format!("class {}\n", object.id));
+            continue;
+        }
+
+        output.push_str(&format!("data class {}(\n", object.id));
+        let idents = unique_idents(object.fields.iter().map(|f| f.name.as_str()), field_ident);
+        for (field, ident) in object.fields.iter().zip(idents) {
+            output.push_str(&format_jsdoc(&field.description, "    "));
+            if ident.trim_matches('`') != field.name {
+                output.push_str(&format!(
+                    "    @SerialName(\"{}\")\n",
+                    field
+                        .name
+                        .replace('\\', "\\\\")
+                        .replace('"', "\\\"")
+                        .replace('$', "\\$")
+                ));
+            }
+
+            let field_type = format_type(&field.field_type);
+            let default = if field_type.ends_with('?') {
+                " = null"
+            } else {
+                ""
+            };
+            output.push_str(&format!("    val {}: {}{},\n", ident, field_type, default));
+        }
+        output.push_str(")\n");
+    }
+
+    output
+}
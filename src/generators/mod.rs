@@ -0,0 +1,250 @@
+mod go;
+mod kotlin;
+mod proto;
+mod python;
+mod rust;
+mod rust_client;
+mod rust_server;
+mod swift;
+mod typescript;
+mod zod;
+
+pub use go::{generate_go, generate_go_client};
+pub use kotlin::generate_kotlin;
+pub use proto::{ProtoLock, generate_proto};
+pub use python::{PythonStyle, generate_python};
+pub use rust::generate_rust;
+pub use rust_client::generate_rust_client;
+pub use rust_server::generate_rust_server;
+pub use swift::generate_swift;
+pub use typescript::{generate_typescript, generate_typescript_client};
+pub use zod::generate_zod;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{Endpoint, Schema, Type};
+
+fn split_words(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+
+    for c in s.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lower = false;
+            continue;
+        }
+
+        if c.is_uppercase() && previous_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+
+        previous_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+pub(crate) fn pascal_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn camel_case(s: &str) -> String {
+    let pascal = pascal_case(s);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+pub(crate) fn snake_case(s: &str) -> String {
+    split_words(s)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+pub(crate) fn path_param_name(segment: &str) -> Option<&str> {
+    if let Some(name) = segment.strip_prefix(':') {
+        return Some(name);
+    }
+
+    segment.strip_prefix('{').and_then(|s| s.strip_suffix('}'))
+}
+
+/// Builds a PascalCase name for an endpoint from its method and path, e.g.
+/// `get "/users/:id"` becomes `GetUsersById`.
+fn endpoint_name(endpoint: &Endpoint) -> String {
+    let mut name = pascal_case(&endpoint.method);
+
+    for segment in endpoint.path.split('/').filter(|s| !s.is_empty()) {
+        match path_param_name(segment) {
+            Some(param) => name.push_str(&format!("By{}", pascal_case(param))),
+            None => name.push_str(&pascal_case(segment)),
+        }
+    }
+
+    if name.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+/// Names every endpoint with [`endpoint_name`], numbering later endpoints
+/// whose name is already taken, e.g. `get "/user/list"` and `get "/userList"`
+/// become `GetUserList` and `GetUserList2`. Names are compared in snake_case so
+/// they also stay distinct once a generator changes their case.
+pub(crate) fn endpoint_names(endpoints: &[Endpoint]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+
+    for endpoint in endpoints {
+        let base = endpoint_name(endpoint);
+        let mut name = base.clone();
+        let mut n = 2;
+        while names.iter().any(|n| snake_case(n) == snake_case(&name)) {
+            name = format!("{}{}", base, n);
+            n += 1;
+        }
+        names.push(name);
+    }
+
+    names
+}
+
+/// Converts each name to an identifier with `ident`, numbering later names
+/// whose identifier is already taken, e.g. `fooBar` and `foo_bar` become
+/// `foo_bar` and `foo_bar_2` in snake_case.
+pub(crate) fn unique_idents<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+    ident: impl Fn(&str) -> String,
+) -> Vec<String> {
+    let mut idents: Vec<String> = Vec::new();
+
+    for name in names {
+        let mut candidate = ident(name);
+        let mut n = 2;
+        while idents.contains(&candidate) {
+            candidate = ident(&format!("{}_{}", name, n));
+            n += 1;
+        }
+        idents.push(candidate);
+    }
+
+    idents
+}
+
+/// Formats a description as `///` doc comment lines, as used by Rust and
+/// Swift.
+pub(crate) fn format_doc(description: &str, indent: &str) -> String {
+    description
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{}///\n", indent)
+            } else {
+                format!("{}/// {}\n", indent, line)
+            }
+        })
+        .collect()
+}
+
+/// Returns the object a type refers to without any heap indirection, if any.
+fn direct_reference(t: &Type) -> Option<&str> {
+    match t {
+        Type::Reference(name) => Some(name),
+        Type::Optional(inner) => direct_reference(inner),
+        _ => None,
+    }
+}
+
+/// Finds the fields that must be stored out of line so that every generated
+/// struct has a finite size, i.e. direct references that lead back to the
+/// same object. Returns `(object, field)` name pairs.
+pub(crate) fn boxed_fields(schema: &Schema) -> HashSet<(String, String)> {
+    let edges: HashMap<&str, Vec<&str>> = schema
+        .objects
+        .iter()
+        .map(|o| {
+            let targets = o
+                .fields
+                .iter()
+                .filter_map(|f| direct_reference(&f.field_type))
+                .collect();
+            (o.id.as_str(), targets)
+        })
+        .collect();
+
+    let reaches = |from: &str, to: &str| {
+        let mut stack = vec![from];
+        let mut seen = HashSet::new();
+
+        while let Some(current) = stack.pop() {
+            if current == to {
+                return true;
+            }
+            if seen.insert(current) {
+                stack.extend(edges.get(current).into_iter().flatten());
+            }
+        }
+
+        false
+    };
+
+    let mut boxed = HashSet::new();
+    for object in &schema.objects {
+        for field in &object.fields {
+            if let Some(target) = direct_reference(&field.field_type)
+                && reaches(target, &object.id)
+            {
+                boxed.insert((object.id.clone(), field.name.clone()));
+            }
+        }
+    }
+
+    boxed
+}
+
+/// Converts a field name into a camelCase identifier, escaping `keywords`
+/// with backticks, as used by Kotlin and Swift.
+pub(crate) fn backtick_ident(name: &str, keywords: &[&str]) -> String {
+    let mut ident = camel_case(name);
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if keywords.contains(&ident.as_str()) {
+        ident = format!("`{}`", ident);
+    }
+
+    ident
+}
+
+pub(crate) fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,274 @@
+use std::fmt::Display;
+
+use crate::{
+    Schema, Type,
+    generators::{snake_case, unique_idents},
+};
+
+/// Field numbers assigned to each message field, keyed by object and field
+/// name. Entries are never removed, so numbers of deleted fields are reserved
+/// instead of being reused.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProtoLock {
+    entries: Vec<(String, String, u32)>,
+}
+
+const LOCK_HEADER: &str = "# Generated by manyleb. Keeps protobuf field numbers stable, commit it with the specification.";
+
+impl ProtoLock {
+    pub fn parse(input: &str) -> Result<ProtoLock, String> {
+        let mut lock = ProtoLock::default();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = line.split_once(' ').and_then(|(message, rest)| {
+                let (field, number) = rest.trim().rsplit_once(' ')?;
+                Some((message, field.trim(), number.parse::<u32>().ok()?))
+            });
+
+            match entry {
+                Some((message, field, number)) if lock.number(message, field).is_none() => lock
+                    .entries
+                    .push((message.to_string(), field.to_string(), number)),
+                Some((message, field, _)) => {
+                    return Err(format!(
+                        "Duplicate entry for field '{}' of message '{}' on line {} of lock file",
+                        field,
+                        message,
+                        i + 1
+                    ));
+                }
+                None => {
+                    return Err(format!(
+                        "Invalid entry on line {} of lock file, expected '<message> <field> <number>'",
+                        i + 1
+                    ));
+                }
+            }
+        }
+
+        Ok(lock)
+    }
+
+    fn number(&self, message: &str, field: &str) -> Option<u32> {
+        self.entries
+            .iter()
+            .find(|(m, f, _)| m == message && f == field)
+            .map(|(_, _, n)| *n)
+    }
+
+    /// Returns the locked number for a field, assigning the next free number
+    /// in the message if it has none yet.
+    fn assign(&mut self, message: &str, field: &str) -> u32 {
+        if let Some(number) = self.number(message, field) {
+            return number;
+        }
+
+        let mut number = self
+            .entries
+            .iter()
+            .filter(|(m, _, _)| m == message)
+            .map(|(_, _, n)| *n)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        // Numbers 19000 to 19999 are reserved by the protobuf implementation.
+        if (19000..20000).contains(&number) {
+            number = 20000;
+        }
+
+        self.entries
+            .push((message.to_string(), field.to_string(), number));
+        number
+    }
+}
+
+impl Display for ProtoLock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", LOCK_HEADER)?;
+        for (message, field, number) in &self.entries {
+            writeln!(f, "{} {} {}", message, field, number)?;
+        }
+        Ok(())
+    }
+}
+
+fn field_ident(name: &str) -> String {
+    let mut ident = snake_case(name);
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert_str(0, "field_");
+    }
+
+    ident
+}
+
+/// The JSON name protoc derives from a field name when none is given.
+fn default_json_name(ident: &str) -> String {
+    let mut json_name = String::new();
+    let mut capitalise = false;
+
+    for c in ident.chars() {
+        if c == '_' {
+            capitalise = true;
+        } else if capitalise {
+            json_name.push(c.to_ascii_uppercase());
+            capitalise = false;
+        } else {
+            json_name.push(c);
+        }
+    }
+
+    json_name
+}
+
+fn format_scalar(t: &Type, context: &str) -> Result<String, String> {
+    Ok(match t {
+        Type::Any => "google.protobuf.Value".to_string(),
+        Type::Null => "google.protobuf.NullValue".to_string(),
+        Type::String => "string".to_string(),
+        Type::Integer => "int64".to_string(),
+        Type::Float => "double".to_string(),
+        Type::Boolean => "bool".to_string(),
+        Type::Reference(name) => name.clone(),
+        Type::Optional(inner_type) => format_scalar(inner_type, context)?,
+        Type::Array(_) | Type::Mapping(_, _) => {
+            return Err(format!(
+                "Type '{}' in {} cannot be nested in protobuf, wrap it in an object",
+                t, context
+            ));
+        }
+    })
+}
+
+/// Formats a field type with its label, e.g. `repeated string` or
+/// `map<string, int64>`.
+fn format_type(t: &Type, context: &str) -> Result<String, String> {
+    match t {
+        Type::Array(item_type) => Ok(format!("repeated {}", format_scalar(item_type, context)?)),
+        Type::Mapping(key_type, value_type) => {
+            let key = match key_type.as_ref() {
+                Type::String => "string",
+                Type::Integer => "int64",
+                Type::Boolean => "bool",
+                _ => {
+                    return Err(format!(
+                        "Mapping key type '{}' in {} is not supported by protobuf, only string, integer and boolean keys are allowed",
+                        key_type, context
+                    ));
+                }
+            };
+            Ok(format!(
+                "map<{}, {}>",
+                key,
+                format_scalar(value_type, context)?
+            ))
+        }
+        Type::Optional(inner_type) => match inner_type.as_ref() {
+            // Repeated and map fields cannot be optional, an absent value is
+            // simply empty.
+            Type::Array(_) | Type::Mapping(_, _) | Type::Optional(_) => {
+                format_type(inner_type, context)
+            }
+            inner_type => Ok(format!("optional {}", format_scalar(inner_type, context)?)),
+        },
+        t => format_scalar(t, context),
+    }
+}
+
+fn type_contains(t: &Type, predicate: &dyn Fn(&Type) -> bool) -> bool {
+    predicate(t)
+        || match t {
+            Type::Array(inner) | Type::Optional(inner) => type_contains(inner, predicate),
+            Type::Mapping(key, value) => {
+                type_contains(key, predicate) || type_contains(value, predicate)
+            }
+            _ => false,
+        }
+}
+
+/// Generates a proto3 file with a message per object. Field numbers are taken
+/// from `lock`, and any new fields are added to it.
+pub fn generate_proto(schema: &Schema, lock: &mut ProtoLock) -> Result<String, String> {
+    let mut output = String::from("// Generated by manyleb. Do not edit by hand.\n");
+    if let Some(title) = &schema.title {
+        output.push_str(&format!("// {}", title));
+        if let Some(version) = &schema.version {
+            output.push_str(&format!(" ({})", version));
+        }
+        output.push('\n');
+    }
+    output.push_str("\nsyntax = \"proto3\";\n\npackage api;\n");
+
+    let uses_struct = schema
+        .objects
+        .iter()
+        .flat_map(|o| o.fields.iter())
+        .any(|f| type_contains(&f.field_type, &|t| matches!(t, Type::Any | Type::Null)));
+    if uses_struct {
+        output.push_str("\nimport \"google/protobuf/struct.proto\";\n");
+    }
+
+    for object in &schema.objects {
+        output.push_str(&format!("\nmessage {} {{\n", object.id));
+
+        let idents = unique_idents(object.fields.iter().map(|f| f.name.as_str()), field_ident);
+        let mut reserved_numbers = Vec::new();
+        let mut reserved_names = Vec::new();
+        for (message, field, number) in &lock.entries {
+            if *message != object.id || object.fields.iter().any(|f| f.name == *field) {
+                continue;
+            }
+
+            reserved_numbers.push(number.to_string());
+            // A field may have been renamed to one with the same identifier.
+            let ident = field_ident(field);
+            if !idents.contains(&ident) && !reserved_names.contains(&format!("\"{}\"", ident)) {
+                reserved_names.push(format!("\"{}\"", ident));
+            }
+        }
+        if !reserved_numbers.is_empty() {
+            output.push_str(&format!("    reserved {};\n", reserved_numbers.join(", ")));
+            if !reserved_names.is_empty() {
+                output.push_str(&format!("    reserved {};\n", reserved_names.join(", ")));
+            }
+        }
+
+        for (i, (field, ident)) in object.fields.iter().zip(&idents).enumerate() {
+            let context = format!("field '{}' of object '{}'", field.name, object.id);
+            let number = lock.assign(&object.id, &field.name);
+
+            let options = if default_json_name(ident) != field.name {
+                format!(" [json_name = \"{}\"]", field.name.escape_default())
+            } else {
+                String::new()
+            };
+
+            if i > 0 || !reserved_numbers.is_empty() {
+                output.push('\n');
+            }
+            for line in field.description.lines() {
+                if line.is_empty() {
+                    output.push_str("    //\n");
+                } else {
+                    output.push_str(&format!("    // {}\n", line));
+                }
+            }
+            output.push_str(&format!(
+                "    {} {} = {}{};\n",
+                format_type(&field.field_type, &context)?,
+                ident,
+                number,
+                options
+            ));
+        }
+
+        output.push_str("}\n");
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+
+use crate::{
+    Field, Schema, Type,
+    generators::{snake_case, unique_idents},
+};
+
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PythonStyle {
+    Pydantic,
+    Dataclasses,
+}
+
+fn field_ident(name: &str) -> String {
+    let mut ident = snake_case(name);
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert_str(0, "field_");
+    }
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+
+    ident
+}
+
+fn format_docstring(description: &str, indent: &str) -> String {
+    let description = description
+        .replace('\\', "\\\\")
+        .replace("\"\"\"", "\\\"\\\"\\\"");
+    let lines: Vec<&str> = description.lines().collect();
+
+    match lines.len() {
+        0 => String::new(),
+        1 => format!("{}\"\"\"{}\"\"\"\n", indent, lines[0]),
+        _ => {
+            let mut result = format!("{}\"\"\"{}\n", indent, lines[0]);
+            for line in &lines[1..] {
+                if line.is_empty() {
+                    result.push('\n');
+                } else {
+                    result.push_str(&format!("{}{}\n", indent, line));
+                }
+            }
+            result.push_str(&format!("{}\"\"\"\n", indent));
+            result
+        }
+    }
+}
+
+/// Formats a type, quoting references to objects that are not yet defined.
+fn format_type(t: &Type, defined: &HashSet<&str>) -> String {
+    match t {
+        Type::Any => "Any".to_string(),
+        Type::Null => "None".to_string(),
+        Type::String => "str".to_string(),
+        Type::Integer => "int".to_string(),
+        Type::Float => "float".to_string(),
+        Type::Boolean => "bool".to_string(),
+        Type::Reference(name) if defined.contains(name.as_str()) => name.clone(),
+        Type::Reference(name) => format!("\"{}\"", name),
+        Type::Array(item_type) => format!("list[{}]", format_type(item_type, defined)),
+        Type::Mapping(key_type, value_type) => format!(
+            "dict[{}, {}]",
+            format_type(key_type, defined),
+            format_type(value_type, defined)
+        ),
+        Type::Optional(inner_type) => format!("Optional[{}]", format_type(inner_type, defined)),
+    }
+}
+
+fn type_contains(t: &Type, predicate: &dyn Fn(&Type) -> bool) -> bool {
+    predicate(t)
+        || match t {
+            Type::Array(inner) | Type::Optional(inner) => type_contains(inner, predicate),
+            Type::Mapping(key, value) => {
+                type_contains(key, predicate) || type_contains(value, predicate)
+            }
+            _ => false,
+        }
+}
+
+fn uses_type(schema: &Schema, predicate: &dyn Fn(&Type) -> bool) -> bool {
+    schema
+        .objects
+        .iter()
+        .flat_map(|o| o.fields.iter())
+        .any(|f| type_contains(&f.field_type, predicate))
+}
+
+fn format_field(field: &Field, ident: &str, style: PythonStyle, defined: &HashSet<&str>) -> String {
+    let field_type = format_type(&field.field_type, defined);
+    let optional = matches!(field.field_type, Type::Optional(_));
+
+    let default = match (style, ident != field.name, optional) {
+        (PythonStyle::Pydantic, true, true) => {
+            format!(
+                " = Field(default=None, alias=\"{}\")",
+                field.name.escape_default()
+            )
+        }
+        (PythonStyle::Pydantic, true, false) => {
+            format!(" = Field(alias=\"{}\")", field.name.escape_default())
+        }
+        (PythonStyle::Dataclasses, true, true) => format!(
+            " = field(default=None, metadata={{\"name\": \"{}\"}})",
+            field.name.escape_default()
+        ),
+        (PythonStyle::Dataclasses, true, false) => format!(
+            " = field(metadata={{\"name\": \"{}\"}})",
+            field.name.escape_default()
+        ),
+        (_, false, true) => " = None".to_string(),
+        (_, false, false) => String::new(),
+    };
+
+    let mut output = format!("    {}: {}{}\n", ident, field_type, default);
+    output.push_str(&format_docstring(&field.description, "    "));
+    output
+}
+
+pub fn generate_python(schema: &Schema, style: PythonStyle) -> String {
+    let mut output = String::from("# Generated by manyleb. Do not edit by hand.\n");
+    if let Some(title) = &schema.title {
+        output.push_str(&format!("# {}", title));
+        if let Some(version) = &schema.version {
+            output.push_str(&format!(" ({})", version));
+        }
+        output.push('\n');
+    }
+    output.push('\n');
+
+    let renamed = schema
+        .objects
+        .iter()
+        .flat_map(|o| o.fields.iter())
+        .any(|f| field_ident(&f.name) != f.name);
+
+    match style {
+        PythonStyle::Pydantic => {}
+        PythonStyle::Dataclasses if renamed => {
+            output.push_str("from dataclasses import dataclass, field\n")
+        }
+        PythonStyle::Dataclasses => output.push_str("from dataclasses import dataclass\n"),
+    }
+
+    let mut typing = Vec::new();
+    if uses_type(schema, &|t| matches!(t, Type::Any)) {
+        typing.push("Any");
+    }
+    if uses_type(schema, &|t| matches!(t, Type::Optional(_))) {
+        typing.push("Optional");
+    }
+    if !typing.is_empty() {
+        output.push_str(&format!("from typing import {}\n", typing.join(", ")));
+    }
+
+    if style == PythonStyle::Pydantic {
+        let imports = if renamed {
+            "BaseModel, ConfigDict, Field"
+        } else {
+            "BaseModel, ConfigDict"
+        };
+        output.push_str(&format!("\nfrom pydantic import {}\n", imports));
+    }
+
+    let mut defined = HashSet::new();
+    let mut forward_references = false;
+
+    for object in &schema.objects {
+        output.push_str("\n\n");
+
+        match style {
+            PythonStyle::Pydantic => {
+                output.push_str(&format!("class {}(BaseModel):\n", object.id));
+                output.push_str(
+                    "    model_config = ConfigDict(populate_by_name=True, use_attribute_docstrings=True)\n",
+                );
+                if !object.fields.is_empty() {
+                    output.push('\n');
+                }
+            }
+            PythonStyle::Dataclasses => {
+                output.push_str("@dataclass(kw_only=True)\n");
+                output.push_str(&format!("class {}:\n", object.id));
+                if object.fields.is_empty() {
+                    output.push_str("    pass\n");
+                }
+            }
+        }
+
+        let idents = unique_idents(object.fields.iter().map(|f| f.name.as_str()), field_ident);
+        for (field, ident) in object.fields.iter().zip(&idents) {
+            forward_references |= type_contains(
+                &field.field_type,
+                &|t| matches!(t, Type::Reference(name) if !defined.contains(name.as_str())),
+            );
+            output.push_str(&format_field(field, ident, style, &defined));
+        }
+
+        defined.insert(object.id.as_str());
+    }
+
+    if style == PythonStyle::Pydantic && forward_references {
+        output.push_str("\n\n");
+        for object in &schema.objects {
+            output.push_str(&format!("{}.model_rebuild()\n", object.id));
+        }
+    }
+
+    output
+}
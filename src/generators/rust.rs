@@ -0,0 +1,215 @@
+use crate::{
+    Endpoint, Field, Schema, Type,
+    generators::{boxed_fields, format_doc, snake_case, unique_idents},
+};
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+    "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Converts a field name into a valid snake_case Rust identifier.
+pub(crate) fn field_ident(name: &str) -> String {
+    let mut ident = snake_case(name);
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert_str(0, "field_");
+    }
+
+    if matches!(ident.as_str(), "self" | "Self" | "crate" | "super") {
+        ident.push('_');
+    } else if KEYWORDS.contains(&ident.as_str()) {
+        ident.insert_str(0, "r#");
+    }
+
+    ident
+}
+
+fn format_key_type(t: &Type) -> String {
+    match t {
+        Type::Integer => "i64".to_string(),
+        Type::Boolean => "bool".to_string(),
+        Type::Optional(inner) => format_key_type(inner),
+        _ => "String".to_string(),
+    }
+}
+
+pub(crate) fn format_type(t: &Type) -> String {
+    match t {
+        Type::Any => "serde_json::Value".to_string(),
+        Type::Null => "()".to_string(),
+        Type::String => "String".to_string(),
+        Type::Integer => "i64".to_string(),
+        Type::Float => "f64".to_string(),
+        Type::Boolean => "bool".to_string(),
+        Type::Reference(name) => name.clone(),
+        Type::Array(item_type) => format!("Vec<{}>", format_type(item_type)),
+        Type::Mapping(key_type, value_type) => format!(
+            "std::collections::HashMap<{}, {}>",
+            format_key_type(key_type),
+            format_type(value_type)
+        ),
+        Type::Optional(inner_type) => format!("Option<{}>", format_type(inner_type)),
+    }
+}
+
+fn format_boxed_type(t: &Type) -> String {
+    match t {
+        Type::Optional(inner) => format!("Option<{}>", format_boxed_type(inner)),
+        t => format!("Box<{}>", format_type(t)),
+    }
+}
+
+pub(crate) fn format_struct_fields(fields: &[Field], boxed: &dyn Fn(&Field) -> bool) -> String {
+    let mut output = String::new();
+    let idents = unique_idents(fields.iter().map(|f| f.name.as_str()), field_ident);
+
+    for (field, ident) in fields.iter().zip(idents) {
+        output.push_str(&format_doc(&field.description, "    "));
+
+        let bare_ident = ident.trim_start_matches("r#");
+        let mut attributes = Vec::new();
+
+        if bare_ident != field.name {
+            attributes.push(format!("rename = \"{}\"", field.name.escape_default()));
+        }
+        if let Type::Optional(_) = field.field_type {
+            attributes.push("default".to_string());
+            attributes.push("skip_serializing_if = \"Option::is_none\"".to_string());
+        }
+        if !attributes.is_empty() {
+            output.push_str(&format!("    #[serde({})]\n", attributes.join(", ")));
+        }
+
+        let field_type = if boxed(field) {
+            format_boxed_type(&field.field_type)
+        } else {
+            format_type(&field.field_type)
+        };
+        output.push_str(&format!("    pub {}: {},\n", ident, field_type));
+    }
+
+    output
+}
+
+pub(crate) fn generate_rust_structs(schema: &Schema) -> String {
+    let boxed = boxed_fields(schema);
+    let mut output = String::new();
+
+    for object in &schema.objects {
+        output.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+        output.push_str(&format!("pub struct {} {{\n", object.id));
+        output.push_str(&format_struct_fields(&object.fields, &|field| {
+            boxed.contains(&(object.id.clone(), field.name.clone()))
+        }));
+        output.push_str("}\n\n");
+    }
+
+    output
+}
+
+pub(crate) fn endpoint_doc(endpoint: &Endpoint) -> String {
+    let mut doc = format!("`{} {}`", endpoint.method.to_uppercase(), endpoint.path);
+    if let Some(description) = &endpoint.description {
+        doc.push_str(&format!("\n\n{}", description));
+    }
+    doc
+}
+
+/// Generates the params and query structs and the response enum shared by
+/// the server and client generators.
+pub(crate) fn generate_endpoint_types(endpoint: &Endpoint, name: &str) -> String {
+    let mut output = String::new();
+
+    if !endpoint.params.is_empty() {
+        output.push_str(&format!(
+            "/// Path parameters for `{} {}`.\n",
+            endpoint.method.to_uppercase(),
+            endpoint.path
+        ));
+        output.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+        output.push_str(&format!("pub struct {}Params {{\n", name));
+        output.push_str(&format_struct_fields(&endpoint.params, &|_| false));
+        output.push_str("}\n\n");
+    }
+
+    if !endpoint.query.is_empty() {
+        output.push_str(&format!(
+            "/// Query parameters for `{} {}`.\n",
+            endpoint.method.to_uppercase(),
+            endpoint.path
+        ));
+        output.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+        output.push_str(&format!("pub struct {}Query {{\n", name));
+        output.push_str(&format_struct_fields(&endpoint.query, &|_| false));
+        output.push_str("}\n\n");
+    }
+
+    output.push_str(&format!(
+        "/// Declared responses for `{} {}`.\n",
+        endpoint.method.to_uppercase(),
+        endpoint.path
+    ));
+    output.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+    output.push_str(&format!("pub enum {}Response {{\n", name));
+    for (status, response_type) in &endpoint.responses {
+        match response_type {
+            Some(t) => output.push_str(&format!("    Status{}({}),\n", status, format_type(t))),
+            None => output.push_str(&format!("    Status{},\n", status)),
+        }
+    }
+    output.push_str("}\n\n");
+
+    output
+}
+
+/// Returns the arguments taken by a generated per-endpoint method, as
+/// `(name, type)` pairs, in the order params, query, body.
+pub(crate) fn endpoint_arguments(endpoint: &Endpoint, name: &str) -> Vec<(&'static str, String)> {
+    let mut arguments = Vec::new();
+
+    if !endpoint.params.is_empty() {
+        arguments.push(("params", format!("{}Params", name)));
+    }
+    if !endpoint.query.is_empty() {
+        arguments.push(("query", format!("{}Query", name)));
+    }
+    if let Some(body_type) = &endpoint.request_body {
+        arguments.push(("body", format_type(body_type)));
+    }
+
+    arguments
+}
+
+pub(crate) fn generate_rust_header(schema: &Schema) -> String {
+    let mut output = String::from("// Generated by manyleb. Do not edit by hand.\n");
+
+    if let Some(title) = &schema.title {
+        output.push_str(&format!("// {}", title));
+        if let Some(version) = &schema.version {
+            output.push_str(&format!(" ({})", version));
+        }
+        output.push('\n');
+    }
+
+    output.push('\n');
+    output
+}
+
+pub fn generate_rust(schema: &Schema) -> String {
+    let mut output = generate_rust_header(schema);
+
+    if !schema.objects.is_empty() {
+        output.push_str("use serde::{Deserialize, Serialize};\n\n");
+    }
+    output.push_str(&generate_rust_structs(schema));
+
+    while output.ends_with("\n\n") {
+        output.pop();
+    }
+
+    output
+}
@@ -0,0 +1,252 @@
+use crate::{
+    Endpoint, Schema,
+    generators::{
+        endpoint_names, format_doc, path_param_name,
+        rust::{
+            endpoint_arguments, endpoint_doc, field_ident, generate_endpoint_types,
+            generate_rust_header, generate_rust_structs,
+        },
+        snake_case, unique_idents,
+    },
+};
+
+const TRANSPORT: &str = r#"/// A request built by the client, with the body already encoded as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    pub method: &'static str,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+/// A raw response returned by a transport.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests over the network, or anywhere else, on behalf of the client.
+pub trait Transport: Send + Sync {
+    type Error;
+
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> impl Future<Output = Result<HttpResponse, Self::Error>> + Send;
+}
+
+#[derive(Debug)]
+pub enum ClientError<E> {
+    /// The transport failed to send the request.
+    Transport(E),
+    /// The request body could not be encoded.
+    Encode(serde_json::Error),
+    /// The response body did not match the declared type.
+    Decode(serde_json::Error),
+    /// The response status code is not declared for the endpoint.
+    UnexpectedStatus(u16, String),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ClientError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Transport(err) => write!(f, "transport error: {}", err),
+            ClientError::Encode(err) => write!(f, "failed to encode request: {}", err),
+            ClientError::Decode(err) => write!(f, "failed to decode response: {}", err),
+            ClientError::UnexpectedStatus(status, _) => {
+                write!(f, "unexpected response status {}", status)
+            }
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for ClientError<E> {}
+"#;
+
+const ENCODE_PATH_SEGMENT: &str = r#"
+fn encode_path_segment(segment: &str) -> String {
+    let mut encoded = String::new();
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+"#;
+
+const ENCODE_QUERY: &str = r#"
+fn encode_query<E>(query: &impl serde::Serialize) -> Result<Vec<(String, String)>, ClientError<E>> {
+    let value = serde_json::to_value(query).map_err(ClientError::Encode)?;
+    let mut pairs = Vec::new();
+
+    if let serde_json::Value::Object(entries) = value {
+        for (key, value) in entries {
+            match value {
+                serde_json::Value::Null => {}
+                serde_json::Value::String(s) => pairs.push((key, s)),
+                other => pairs.push((key, other.to_string())),
+            }
+        }
+    }
+
+    Ok(pairs)
+}
+"#;
+
+const DECODE: &str = r#"
+fn decode<T: serde::de::DeserializeOwned, E>(body: &str) -> Result<T, ClientError<E>> {
+    serde_json::from_str(body).map_err(ClientError::Decode)
+}
+"#;
+
+fn format_path(endpoint: &Endpoint) -> String {
+    let mut arguments = Vec::new();
+    let idents = unique_idents(endpoint.params.iter().map(|p| p.name.as_str()), field_ident);
+
+    let template = endpoint
+        .path
+        .split('/')
+        .map(|segment| {
+            let param = path_param_name(segment)
+                .and_then(|name| endpoint.params.iter().position(|p| p.name == name));
+
+            match param {
+                Some(index) => {
+                    arguments.push(format!(
+                        "encode_path_segment(&params.{}.to_string())",
+                        idents[index]
+                    ));
+                    "{}".to_string()
+                }
+                None => segment.replace('{', "{{").replace('}', "}}"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    let template = format!("\"{}\"", template.escape_default());
+
+    if arguments.is_empty() {
+        format!("{}.to_string()", template)
+    } else {
+        format!("format!({}, {})", template, arguments.join(", "))
+    }
+}
+
+pub fn generate_rust_client(schema: &Schema) -> String {
+    let mut output = generate_rust_header(schema);
+
+    let names = endpoint_names(&schema.endpoints);
+    let uses = |argument: &str| {
+        schema.endpoints.iter().zip(&names).any(|(e, name)| {
+            endpoint_arguments(e, name)
+                .iter()
+                .any(|(a, _)| *a == argument)
+        })
+    };
+
+    output.push_str("use std::future::Future;\n\n");
+    if !schema.objects.is_empty() || uses("params") || uses("query") {
+        output.push_str("use serde::{Deserialize, Serialize};\n\n");
+    }
+
+    output.push_str(TRANSPORT);
+    if uses("params") {
+        output.push_str(ENCODE_PATH_SEGMENT);
+    }
+    if uses("query") {
+        output.push_str(ENCODE_QUERY);
+    }
+    if schema
+        .endpoints
+        .iter()
+        .any(|e| e.responses.iter().any(|(_, t)| t.is_some()))
+    {
+        output.push_str(DECODE);
+    }
+    output.push('\n');
+
+    output.push_str(&generate_rust_structs(schema));
+
+    for (endpoint, name) in schema.endpoints.iter().zip(&names) {
+        output.push_str(&generate_endpoint_types(endpoint, name));
+    }
+
+    output.push_str("/// Client with one method per endpoint in the specification.\n");
+    output.push_str("pub struct Client<T: Transport> {\n");
+    output.push_str("    transport: T,\n");
+    output.push_str("}\n\n");
+    output.push_str("impl<T: Transport> Client<T> {\n");
+    output.push_str("    pub fn new(transport: T) -> Self {\n");
+    output.push_str("        Client { transport }\n");
+    output.push_str("    }\n");
+
+    for (endpoint, name) in schema.endpoints.iter().zip(&names) {
+        let mut arguments = vec!["&self".to_string()];
+        for (argument, argument_type) in endpoint_arguments(endpoint, name) {
+            arguments.push(format!("{}: {}", argument, argument_type));
+        }
+
+        output.push('\n');
+        output.push_str(&format_doc(&endpoint_doc(endpoint), "    "));
+        output.push_str(&format!(
+            "    pub async fn {}(\n        {},\n    ) -> Result<{}Response, ClientError<T::Error>> {{\n",
+            snake_case(name),
+            arguments.join(",\n        "),
+            name
+        ));
+
+        output.push_str("        let request = HttpRequest {\n");
+        output.push_str(&format!(
+            "            method: \"{}\",\n",
+            endpoint.method.to_uppercase()
+        ));
+        output.push_str(&format!("            path: {},\n", format_path(endpoint)));
+        if endpoint.query.is_empty() {
+            output.push_str("            query: Vec::new(),\n");
+        } else {
+            output.push_str("            query: encode_query(&query)?,\n");
+        }
+        if endpoint.request_body.is_some() {
+            output.push_str(
+                "            body: Some(serde_json::to_string(&body).map_err(ClientError::Encode)?),\n",
+            );
+        } else {
+            output.push_str("            body: None,\n");
+        }
+        output.push_str("        };\n\n");
+
+        output.push_str("        let response = self\n");
+        output.push_str("            .transport\n");
+        output.push_str("            .send(request)\n");
+        output.push_str("            .await\n");
+        output.push_str("            .map_err(ClientError::Transport)?;\n\n");
+
+        output.push_str("        match response.status {\n");
+        for (status, response_type) in &endpoint.responses {
+            match response_type {
+                Some(_) => output.push_str(&format!(
+                    "            {} => Ok({}Response::Status{}(decode(&response.body)?)),\n",
+                    status, name, status
+                )),
+                None => output.push_str(&format!(
+                    "            {} => Ok({}Response::Status{}),\n",
+                    status, name, status
+                )),
+            }
+        }
+        output.push_str(
+            "            status => Err(ClientError::UnexpectedStatus(status, response.body)),\n",
+        );
+        output.push_str("        }\n");
+        output.push_str("    }\n");
+    }
+
+    output.push_str("}\n");
+    output
+}
@@ -0,0 +1,166 @@
+use crate::{
+    Schema,
+    generators::{
+        endpoint_names, format_doc, path_param_name,
+        rust::{
+            endpoint_arguments, endpoint_doc, generate_endpoint_types, generate_rust_header,
+            generate_rust_structs,
+        },
+        snake_case,
+    },
+};
+
+fn format_router_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| match path_param_name(segment) {
+            Some(name) => format!("{{{}}}", name),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+pub fn generate_rust_server(schema: &Schema) -> String {
+    let mut output = generate_rust_header(schema);
+
+    let has_endpoints = !schema.endpoints.is_empty();
+    let names = endpoint_names(&schema.endpoints);
+    let uses = |argument: &str| {
+        schema.endpoints.iter().zip(&names).any(|(e, name)| {
+            endpoint_arguments(e, name)
+                .iter()
+                .any(|(a, _)| *a == argument)
+        })
+    };
+    let uses_json = uses("body")
+        || schema
+            .endpoints
+            .iter()
+            .any(|e| e.responses.iter().any(|(_, t)| t.is_some()));
+
+    let mut extract = Vec::new();
+    if uses("params") {
+        extract.push("Path");
+    }
+    if uses("query") {
+        extract.push("Query");
+    }
+    if has_endpoints {
+        extract.push("State");
+    }
+
+    output.push_str("use std::{future::Future, sync::Arc};\n\n");
+    output.push_str("use axum::{\n");
+    output.push_str(if uses_json {
+        "    Json, Router,\n"
+    } else {
+        "    Router,\n"
+    });
+    if !extract.is_empty() {
+        output.push_str(&format!("    extract::{{{}}},\n", extract.join(", ")));
+    }
+    if has_endpoints {
+        output.push_str("    http::StatusCode,\n");
+        output.push_str("    response::{IntoResponse, Response},\n");
+        output.push_str("    routing::{MethodFilter, on},\n");
+    }
+    output.push_str("};\n");
+    if !schema.objects.is_empty() || uses("params") || uses("query") {
+        output.push_str("use serde::{Deserialize, Serialize};\n");
+    }
+    output.push('\n');
+
+    output.push_str(&generate_rust_structs(schema));
+
+    for (endpoint, name) in schema.endpoints.iter().zip(&names) {
+        output.push_str(&generate_endpoint_types(endpoint, name));
+
+        output.push_str(&format!("impl IntoResponse for {}Response {{\n", name));
+        output.push_str("    fn into_response(self) -> Response {\n");
+        output.push_str("        match self {\n");
+        for (status, response_type) in &endpoint.responses {
+            let code = format!("StatusCode::from_u16({}).unwrap()", status);
+            match response_type {
+                Some(_) => output.push_str(&format!(
+                    "            Self::Status{}(body) => ({}, Json(body)).into_response(),\n",
+                    status, code
+                )),
+                None => output.push_str(&format!(
+                    "            Self::Status{} => {}.into_response(),\n",
+                    status, code
+                )),
+            }
+        }
+        output.push_str("        }\n");
+        output.push_str("    }\n");
+        output.push_str("}\n\n");
+    }
+
+    output.push_str("/// Server contract with one method per endpoint in the specification.\n");
+    output.push_str("pub trait Api: Send + Sync + 'static {\n");
+    for (i, (endpoint, name)) in schema.endpoints.iter().zip(&names).enumerate() {
+        let mut arguments = vec!["&self".to_string()];
+        for (argument, argument_type) in endpoint_arguments(endpoint, name) {
+            arguments.push(format!("{}: {}", argument, argument_type));
+        }
+
+        if i > 0 {
+            output.push('\n');
+        }
+        output.push_str(&format_doc(&endpoint_doc(endpoint), "    "));
+        output.push_str(&format!(
+            "    fn {}(\n        {},\n    ) -> impl Future<Output = {}Response> + Send;\n",
+            snake_case(name),
+            arguments.join(",\n        "),
+            name
+        ));
+    }
+    output.push_str("}\n\n");
+
+    for (endpoint, name) in schema.endpoints.iter().zip(&names) {
+        let arguments = endpoint_arguments(endpoint, name);
+
+        let mut extractors = vec!["State(api): State<Arc<T>>".to_string()];
+        for (argument, argument_type) in &arguments {
+            let extractor = match *argument {
+                "params" => "Path",
+                "query" => "Query",
+                _ => "Json",
+            };
+            extractors.push(format!(
+                "{}({}): {}<{}>",
+                extractor, argument, extractor, argument_type
+            ));
+        }
+
+        let call_arguments: Vec<&str> = arguments.iter().map(|(argument, _)| *argument).collect();
+
+        output.push_str(&format!(
+            "async fn handle_{}<T: Api>(\n    {},\n) -> {}Response {{\n",
+            snake_case(name),
+            extractors.join(",\n    "),
+            name
+        ));
+        output.push_str(&format!(
+            "    api.{}({}).await\n}}\n\n",
+            snake_case(name),
+            call_arguments.join(", ")
+        ));
+    }
+
+    output.push_str("/// Builds a router that extracts each request and dispatches it to `api`.\n");
+    output.push_str("pub fn router<T: Api>(api: T) -> Router {\n");
+    output.push_str("    Router::new()\n");
+    for (endpoint, name) in schema.endpoints.iter().zip(&names) {
+        output.push_str(&format!(
+            "        .route(\n            \"{}\",\n            on(MethodFilter::{}, handle_{}::<T>),\n        )\n",
+            format_router_path(&endpoint.path),
+            endpoint.method.to_uppercase(),
+            snake_case(name)
+        ));
+    }
+    output.push_str("        .with_state(Arc::new(api))\n");
+    output.push_str("}\n");
+
+    output
+}
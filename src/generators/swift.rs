@@ -0,0 +1,319 @@
+use crate::{
+    Schema, Type,
+    generators::{backtick_ident, boxed_fields, format_doc, unique_idents},
+};
+
+const KEYWORDS: &[&str] = &[
+    "Any",
+    "Self",
+    "as",
+    "associatedtype",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "open",
+    "operator",
+    "precedencegroup",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+
+const JSON_VALUE: &str = r#"
+/// An arbitrary JSON value.
+public enum JSONValue: Codable, Equatable {
+    case null
+    case bool(Bool)
+    case number(Double)
+    case string(String)
+    case array([JSONValue])
+    case object([String: JSONValue])
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([JSONValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: JSONValue].self))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null: try container.encodeNil()
+        case .bool(let value): try container.encode(value)
+        case .number(let value): try container.encode(value)
+        case .string(let value): try container.encode(value)
+        case .array(let value): try container.encode(value)
+        case .object(let value): try container.encode(value)
+        }
+    }
+}
+"#;
+
+const JSON_NULL: &str = r#"
+/// A placeholder for values that are always JSON `null`.
+public struct JSONNull: Codable, Equatable {
+    public init() {}
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if !container.decodeNil() {
+            throw DecodingError.typeMismatch(
+                JSONNull.self,
+                DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Expected null")
+            )
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encodeNil()
+    }
+}
+"#;
+
+const INDIRECT: &str = r#"
+/// Stores a value out of line, so that structs can contain themselves.
+@propertyWrapper
+public enum Indirect<T: Codable>: Codable {
+    indirect case wrapped(T)
+
+    public init(wrappedValue: T) {
+        self = .wrapped(wrappedValue)
+    }
+
+    public var wrappedValue: T {
+        get {
+            switch self {
+            case .wrapped(let value): return value
+            }
+        }
+        set { self = .wrapped(newValue) }
+    }
+
+    public init(from decoder: Decoder) throws {
+        self = .wrapped(try T(from: decoder))
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        try wrappedValue.encode(to: encoder)
+    }
+}
+
+extension KeyedDecodingContainer {
+    /// Decodes a missing indirect optional as `nil`, like a plain optional.
+    public func decode<T: Codable>(_ type: Indirect<T?>.Type, forKey key: Key) throws -> Indirect<T?> {
+        Indirect(wrappedValue: try decodeIfPresent(T.self, forKey: key))
+    }
+}
+
+extension KeyedEncodingContainer {
+    /// Leaves out an indirect optional that is `nil`, like a plain optional.
+    public mutating func encode<T: Codable>(_ value: Indirect<T?>, forKey key: Key) throws {
+        try encodeIfPresent(value.wrappedValue, forKey: key)
+    }
+}
+"#;
+
+fn field_ident(name: &str) -> String {
+    backtick_ident(name, KEYWORDS)
+}
+
+fn format_key_type(t: &Type) -> String {
+    match t {
+        Type::Integer => "Int".to_string(),
+        Type::Optional(inner) => format_key_type(inner),
+        _ => "String".to_string(),
+    }
+}
+
+fn format_type(t: &Type) -> String {
+    match t {
+        Type::Any => "JSONValue".to_string(),
+        Type::Null => "JSONNull?".to_string(),
+        Type::String => "String".to_string(),
+        Type::Integer => "Int".to_string(),
+        Type::Float => "Double".to_string(),
+        Type::Boolean => "Bool".to_string(),
+        Type::Reference(name) => name.clone(),
+        Type::Array(item_type) => format!("[{}]", format_type(item_type)),
+        Type::Mapping(key_type, value_type) => format!(
+            "[{}: {}]",
+            format_key_type(key_type),
+            format_type(value_type)
+        ),
+        Type::Optional(inner_type) => match inner_type.as_ref() {
+            Type::Null | Type::Optional(_) => format_type(inner_type),
+            inner_type => format!("{}?", format_type(inner_type)),
+        },
+    }
+}
+
+fn type_contains(t: &Type, predicate: &dyn Fn(&Type) -> bool) -> bool {
+    predicate(t)
+        || match t {
+            Type::Array(inner) | Type::Optional(inner) => type_contains(inner, predicate),
+            Type::Mapping(key, value) => {
+                type_contains(key, predicate) || type_contains(value, predicate)
+            }
+            _ => false,
+        }
+}
+
+pub fn generate_swift(schema: &Schema) -> String {
+    let mut output = String::from("// Generated by manyleb. Do not edit by hand.\n");
+    if let Some(title) = &schema.title {
+        output.push_str(&format!("// {}", title));
+        if let Some(version) = &schema.version {
+            output.push_str(&format!(" ({})", version));
+        }
+        output.push('\n');
+    }
+    output.push_str("\nimport Foundation\n");
+
+    let uses = |predicate: &dyn Fn(&Type) -> bool| {
+        schema
+            .objects
+            .iter()
+            .flat_map(|o| o.fields.iter())
+            .any(|f| type_contains(&f.field_type, predicate))
+    };
+    if uses(&|t| matches!(t, Type::Any)) {
+        output.push_str(JSON_VALUE);
+    }
+    if uses(&|t| matches!(t, Type::Null)) {
+        output.push_str(JSON_NULL);
+    }
+
+    // Structs cannot contain themselves, so fields that lead back to their
+    // own object are stored out of line with `@Indirect`.
+    let boxed = boxed_fields(schema);
+    if !boxed.is_empty() {
+        output.push_str(INDIRECT);
+    }
+
+    for object in &schema.objects {
+        let idents = unique_idents(object.fields.iter().map(|f| f.name.as_str()), field_ident);
+        let fields: Vec<(String, String)> = object
+            .fields
+            .iter()
+            .zip(idents)
+            .map(|(f, ident)| (ident, format_type(&f.field_type)))
+            .collect();
+
+        output.push('\n');
+        output.push_str(&format!("public struct {}: Codable {{\n", object.id));
+
+        for (field, (ident, field_type)) in object.fields.iter().zip(&fields) {
+            output.push_str(&format_doc(&field.description, "    "));
+            if boxed.contains(&(object.id.clone(), field.name.clone())) {
+                output.push_str("    @Indirect\n");
+            }
+            output.push_str(&format!("    public var {}: {}\n", ident, field_type));
+        }
+
+        if object
+            .fields
+            .iter()
+            .zip(&fields)
+            .any(|(field, (ident, _))| ident.trim_matches('`') != field.name)
+        {
+            output.push_str("\n    enum CodingKeys: String, CodingKey {\n");
+            for (field, (ident, _)) in object.fields.iter().zip(&fields) {
+                if ident.trim_matches('`') == field.name {
+                    output.push_str(&format!("        case {}\n", ident));
+                } else {
+                    output.push_str(&format!(
+                        "        case {} = \"{}\"\n",
+                        ident,
+                        field.name.replace('\\', "\\\\").replace('"', "\\\"")
+                    ));
+                }
+            }
+            output.push_str("    }\n");
+        }
+
+        let parameters: Vec<String> = fields
+            .iter()
+            .map(|(ident, field_type)| {
+                if field_type.ends_with('?') {
+                    format!("{}: {} = nil", ident, field_type)
+                } else {
+                    format!("{}: {}", ident, field_type)
+                }
+            })
+            .collect();
+
+        if !object.fields.is_empty() {
+            output.push('\n');
+        }
+        if parameters.is_empty() {
+            output.push_str("    public init() {}\n");
+        } else {
+            output.push_str(&format!(
+                "    public init(\n        {}\n    ) {{\n",
+                parameters.join(",\n        ")
+            ));
+            for (ident, _) in &fields {
+                output.push_str(&format!("        self.{} = {}\n", ident, ident));
+            }
+            output.push_str("    }\n");
+        }
+
+        output.push_str("}\n");
+    }
+
+    output
+}
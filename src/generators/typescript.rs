@@ -0,0 +1,426 @@
+use crate::{
+    Field, Schema, Type,
+    generators::{camel_case, endpoint_names, is_identifier, path_param_name},
+};
+
+const KEYWORDS: &[&str] = &[
+    "arguments",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Converts an endpoint name into a camelCase function name, appending `_` to
+/// reserved words, e.g. `route delete "/"` becomes `delete_`.
+fn function_ident(name: &str) -> String {
+    let mut ident = camel_case(name);
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    ident
+}
+
+pub(crate) fn format_jsdoc(description: &str, indent: &str) -> String {
+    if description.is_empty() {
+        return String::new();
+    }
+
+    let description = description.replace("*/", "*\\/");
+    let lines: Vec<&str> = description.lines().collect();
+
+    if lines.len() == 1 {
+        return format!("{}/** {} */\n", indent, lines[0]);
+    }
+
+    let mut result = format!("{}/**\n", indent);
+    for line in lines {
+        if line.is_empty() {
+            result.push_str(&format!("{} *\n", indent));
+        } else {
+            result.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    result.push_str(&format!("{} */\n", indent));
+    result
+}
+
+pub(crate) fn format_property_name(name: &str) -> String {
+    if is_identifier(name) {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+pub(crate) fn format_type(t: &Type) -> String {
+    match t {
+        Type::Any => "unknown".to_string(),
+        Type::Null => "null".to_string(),
+        Type::String => "string".to_string(),
+        Type::Integer | Type::Float => "number".to_string(),
+        Type::Boolean => "boolean".to_string(),
+        Type::Reference(name) => name.clone(),
+        Type::Array(item_type) => match item_type.as_ref() {
+            Type::Optional(_) => format!("({})[]", format_type(item_type)),
+            _ => format!("{}[]", format_type(item_type)),
+        },
+        Type::Mapping(key_type, value_type) => {
+            let key = match key_type.as_ref() {
+                Type::Integer | Type::Float => "number",
+                _ => "string",
+            };
+            format!("Record<{}, {}>", key, format_type(value_type))
+        }
+        Type::Optional(inner_type) => format!("{} | null", format_type(inner_type)),
+    }
+}
+
+pub(crate) fn format_fields(fields: &[Field], indent: &str) -> String {
+    let mut result = String::new();
+
+    for field in fields {
+        result.push_str(&format_jsdoc(&field.description, indent));
+
+        match &field.field_type {
+            Type::Optional(inner_type) => result.push_str(&format!(
+                "{}{}?: {};\n",
+                indent,
+                format_property_name(&field.name),
+                format_type(inner_type)
+            )),
+            field_type => result.push_str(&format!(
+                "{}{}: {};\n",
+                indent,
+                format_property_name(&field.name),
+                format_type(field_type)
+            )),
+        }
+    }
+
+    result
+}
+
+pub fn generate_typescript(schema: &Schema) -> String {
+    let mut output = String::new();
+
+    output.push_str("// Generated by manyleb. Do not edit by hand.\n");
+    if let Some(title) = &schema.title {
+        output.push_str(&format!("// {}", title));
+        if let Some(version) = &schema.version {
+            output.push_str(&format!(" ({})", version));
+        }
+        output.push('\n');
+    }
+    output.push('\n');
+
+    for object in &schema.objects {
+        output.push_str(&format!("export interface {} {{\n", object.id));
+        output.push_str(&format_fields(&object.fields, "    "));
+        output.push_str("}\n\n");
+    }
+
+    for (endpoint, name) in schema
+        .endpoints
+        .iter()
+        .zip(endpoint_names(&schema.endpoints))
+    {
+        let mut doc = format!("{} {}", endpoint.method.to_uppercase(), endpoint.path);
+        if let Some(description) = &endpoint.description {
+            doc.push_str(&format!("\n\n{}", description));
+        }
+        output.push_str(&format_jsdoc(&doc, ""));
+
+        if !endpoint.params.is_empty() {
+            output.push_str(&format!("export interface {}Params {{\n", name));
+            output.push_str(&format_fields(&endpoint.params, "    "));
+            output.push_str("}\n\n");
+        }
+
+        if !endpoint.query.is_empty() {
+            output.push_str(&format!("export interface {}Query {{\n", name));
+            output.push_str(&format_fields(&endpoint.query, "    "));
+            output.push_str("}\n\n");
+        }
+
+        if let Some(body_type) = &endpoint.request_body {
+            output.push_str(&format!(
+                "export type {}RequestBody = {};\n\n",
+                name,
+                format_type(body_type)
+            ));
+        }
+
+        output.push_str(&format!("export interface {}Responses {{\n", name));
+        for (status, response_type) in &endpoint.responses {
+            let response_type = match response_type {
+                Some(t) => format_type(t),
+                None => "undefined".to_string(),
+            };
+            output.push_str(&format!("    {}: {};\n", status, response_type));
+        }
+        output.push_str("}\n\n");
+    }
+
+    output.push_str("export interface Endpoints {\n");
+    for (endpoint, name) in schema
+        .endpoints
+        .iter()
+        .zip(endpoint_names(&schema.endpoints))
+    {
+        let params = if endpoint.params.is_empty() {
+            "undefined".to_string()
+        } else {
+            format!("{}Params", name)
+        };
+        let query = if endpoint.query.is_empty() {
+            "undefined".to_string()
+        } else {
+            format!("{}Query", name)
+        };
+        let body = if endpoint.request_body.is_some() {
+            format!("{}RequestBody", name)
+        } else {
+            "undefined".to_string()
+        };
+
+        output.push_str(&format!(
+            "    \"{} {}\": {{\n",
+            endpoint.method.to_uppercase(),
+            endpoint.path
+        ));
+        output.push_str(&format!("        params: {};\n", params));
+        output.push_str(&format!("        query: {};\n", query));
+        output.push_str(&format!("        body: {};\n", body));
+        output.push_str(&format!("        responses: {}Responses;\n", name));
+        output.push_str("    };\n");
+    }
+    output.push_str("}\n");
+
+    output
+}
+
+const CLIENT_RUNTIME: &str = r#"export interface ClientOptions {
+    /** Base URL of the API, e.g. "https://api.example.com". */
+    baseUrl: string;
+    /** Headers sent with every request. */
+    headers?: Record<string, string>;
+    /** Custom fetch implementation, defaults to the global fetch. */
+    fetch?: typeof fetch;
+}
+
+export class UnexpectedResponseError extends Error {
+    constructor(public readonly status: number, public readonly body: unknown) {
+        super(`Unexpected response status ${status}`);
+    }
+}
+
+async function request(
+    options: ClientOptions,
+    method: string,
+    path: string,
+    statuses: number[],
+    query?: object,
+    body?: unknown,
+): Promise<{ status: number; body: unknown }> {
+    let url = options.baseUrl.replace(/\/+$/, "") + path;
+
+    if (query !== undefined) {
+        const search = new URLSearchParams();
+        for (const [key, value] of Object.entries(query)) {
+            if (value !== undefined && value !== null) {
+                search.append(key, String(value));
+            }
+        }
+        const encoded = search.toString();
+        if (encoded) {
+            url += `?${encoded}`;
+        }
+    }
+
+    const headers: Record<string, string> = { Accept: "application/json", ...options.headers };
+    if (body !== undefined) {
+        headers["Content-Type"] = "application/json";
+    }
+
+    const response = await (options.fetch ?? fetch)(url, {
+        method,
+        headers,
+        body: body === undefined ? undefined : JSON.stringify(body),
+    });
+
+    const text = await response.text();
+    const parsed = text ? JSON.parse(text) : undefined;
+
+    if (!statuses.includes(response.status)) {
+        throw new UnexpectedResponseError(response.status, parsed);
+    }
+
+    return { status: response.status, body: parsed };
+}
+"#;
+
+fn format_path_template(path: &str) -> String {
+    let segments: Vec<String> = path
+        .split('/')
+        .map(|segment| match path_param_name(segment) {
+            Some(name) => format!(
+                "${{encodeURIComponent(String(params{}))}}",
+                if is_identifier(name) {
+                    format!(".{}", name)
+                } else {
+                    format!("[{}]", format_property_name(name))
+                }
+            ),
+            None => segment
+                .replace('\\', "\\\\")
+                .replace('`', "\\`")
+                .replace("${", "\\${"),
+        })
+        .collect();
+
+    format!("`{}`", segments.join("/"))
+}
+
+pub fn generate_typescript_client(schema: &Schema) -> String {
+    let mut output = generate_typescript(schema);
+
+    output.push('\n');
+    output.push_str(CLIENT_RUNTIME);
+
+    for (endpoint, name) in schema
+        .endpoints
+        .iter()
+        .zip(endpoint_names(&schema.endpoints))
+    {
+        output.push('\n');
+        output.push_str(&format!("export type {}Result =\n", name));
+        if endpoint.responses.is_empty() {
+            output.push_str("    never;\n");
+        }
+        for (i, (status, _)) in endpoint.responses.iter().enumerate() {
+            output.push_str(&format!(
+                "    | {{ status: {}; body: {}Responses[{}] }}{}\n",
+                status,
+                name,
+                status,
+                if i + 1 == endpoint.responses.len() {
+                    ";"
+                } else {
+                    ""
+                }
+            ));
+        }
+        output.push('\n');
+
+        // (name, type, optional) for each argument after the client options.
+        let mut parameters = Vec::new();
+        if !endpoint.params.is_empty() {
+            parameters.push(("params", format!("{}Params", name), false));
+        }
+        if !endpoint.query.is_empty() {
+            let all_optional = endpoint
+                .query
+                .iter()
+                .all(|q| matches!(q.field_type, Type::Optional(_)));
+            parameters.push(("query", format!("{}Query", name), all_optional));
+        }
+        if let Some(body_type) = &endpoint.request_body {
+            let optional = matches!(body_type, Type::Optional(_));
+            parameters.push(("body", format!("{}RequestBody", name), optional));
+        }
+
+        let mut arguments = vec!["options: ClientOptions".to_string()];
+        for (i, (argument, argument_type, optional)) in parameters.iter().enumerate() {
+            let trailing = parameters[i..].iter().all(|(_, _, optional)| *optional);
+            if *optional && trailing {
+                arguments.push(format!("{}?: {}", argument, argument_type));
+            } else if *optional {
+                arguments.push(format!("{}: {} | undefined", argument, argument_type));
+            } else {
+                arguments.push(format!("{}: {}", argument, argument_type));
+            }
+        }
+
+        let mut doc = format!("{} {}", endpoint.method.to_uppercase(), endpoint.path);
+        if let Some(description) = &endpoint.description {
+            doc.push_str(&format!("\n\n{}", description));
+        }
+        output.push_str(&format_jsdoc(&doc, ""));
+
+        let statuses: Vec<String> = endpoint
+            .responses
+            .iter()
+            .map(|(status, _)| status.to_string())
+            .collect();
+
+        output.push_str(&format!(
+            "export async function {}(\n    {},\n): Promise<{}Result> {{\n",
+            function_ident(&name),
+            arguments.join(",\n    "),
+            name
+        ));
+        output.push_str(&format!(
+            "    const response = await request(\n        options,\n        \"{}\",\n        {},\n        [{}],\n        {},\n        {},\n    );\n",
+            endpoint.method.to_uppercase(),
+            format_path_template(&endpoint.path),
+            statuses.join(", "),
+            if endpoint.query.is_empty() {
+                "undefined"
+            } else {
+                "query"
+            },
+            if endpoint.request_body.is_some() {
+                "body"
+            } else {
+                "undefined"
+            }
+        ));
+        output.push_str(&format!("    return response as {}Result;\n}}\n", name));
+    }
+
+    output
+}
@@ -0,0 +1,261 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    Field, Schema, Type,
+    generators::{
+        endpoint_names,
+        typescript::{format_jsdoc, format_property_name, format_type},
+    },
+};
+
+const RUNTIME: &str = r#"/** Union of `{ status, body }` pairs for a map of response schemas. */
+export type ResponseOf<R extends Record<number, z.ZodType>> = {
+    [S in keyof R]: { status: S; body: z.infer<R[S]> };
+}[keyof R];
+
+/** Validates a response body against the schema declared for its status code. */
+export function parseResponse<R extends Record<number, z.ZodType>>(
+    schemas: R,
+    status: number,
+    body: unknown,
+): ResponseOf<R> {
+    const schema = schemas[status];
+    if (schema === undefined) {
+        throw new Error(`Undeclared response status ${status}`);
+    }
+    return { status, body: schema.parse(body) } as ResponseOf<R>;
+}
+"#;
+
+/// Parses `"true"` and `"false"`. `z.coerce.boolean()` would turn any
+/// non-empty string, including `"false"`, into `true`.
+const COERCE_BOOLEAN: &str =
+    r#"z.preprocess((v) => (v === "true" ? true : v === "false" ? false : v), z.boolean())"#;
+
+fn references<'a>(t: &'a Type, found: &mut Vec<&'a str>) {
+    match t {
+        Type::Reference(name) => found.push(name),
+        Type::Array(inner) | Type::Optional(inner) => references(inner, found),
+        Type::Mapping(key, value) => {
+            references(key, found);
+            references(value, found);
+        }
+        _ => {}
+    }
+}
+
+/// Finds objects that can reach themselves through their fields. Their types
+/// cannot be inferred by TypeScript, so they are declared explicitly.
+fn recursive_objects(schema: &Schema) -> HashSet<&str> {
+    let edges: HashMap<&str, Vec<&str>> = schema
+        .objects
+        .iter()
+        .map(|o| {
+            let mut targets = Vec::new();
+            for field in &o.fields {
+                references(&field.field_type, &mut targets);
+            }
+            (o.id.as_str(), targets)
+        })
+        .collect();
+
+    let reaches_itself = |from: &str| {
+        let mut stack: Vec<&str> = edges.get(from).into_iter().flatten().copied().collect();
+        let mut seen = HashSet::new();
+
+        while let Some(current) = stack.pop() {
+            if current == from {
+                return true;
+            }
+            if seen.insert(current) {
+                stack.extend(edges.get(current).into_iter().flatten());
+            }
+        }
+
+        false
+    };
+
+    schema
+        .objects
+        .iter()
+        .map(|o| o.id.as_str())
+        .filter(|id| reaches_itself(id))
+        .collect()
+}
+
+/// Formats a zod schema, deferring references to objects that are not yet
+/// defined with `z.lazy`. With `coerce`, numbers and booleans are also
+/// accepted as the strings that path and query parameters arrive as.
+fn format_schema(t: &Type, defined: &HashSet<&str>, coerce: bool) -> String {
+    match t {
+        Type::Any => "z.unknown()".to_string(),
+        Type::Null => "z.null()".to_string(),
+        Type::String => "z.string()".to_string(),
+        Type::Integer if coerce => "z.coerce.number().int()".to_string(),
+        Type::Integer => "z.number().int()".to_string(),
+        Type::Float if coerce => "z.coerce.number()".to_string(),
+        Type::Float => "z.number()".to_string(),
+        Type::Boolean if coerce => COERCE_BOOLEAN.to_string(),
+        Type::Boolean => "z.boolean()".to_string(),
+        Type::Reference(name) if defined.contains(name.as_str()) => format!("{}Schema", name),
+        Type::Reference(name) => format!("z.lazy(() => {}Schema)", name),
+        Type::Array(item_type) => format!("z.array({})", format_schema(item_type, defined, coerce)),
+        // JSON object keys are always strings, whatever the declared key type.
+        Type::Mapping(_, value_type) => {
+            format!(
+                "z.record(z.string(), {})",
+                format_schema(value_type, defined, coerce)
+            )
+        }
+        Type::Optional(inner_type) => {
+            format!("{}.nullable()", format_schema(inner_type, defined, coerce))
+        }
+    }
+}
+
+fn format_object_schema(fields: &[Field], defined: &HashSet<&str>, coerce: bool) -> String {
+    if fields.is_empty() {
+        return "z.object({})".to_string();
+    }
+
+    let mut output = String::from("z.object({\n");
+    for field in fields {
+        output.push_str(&format_jsdoc(&field.description, "    "));
+
+        let field_schema = match &field.field_type {
+            Type::Optional(inner_type) => {
+                format!("{}.nullish()", format_schema(inner_type, defined, coerce))
+            }
+            field_type => format_schema(field_type, defined, coerce),
+        };
+        output.push_str(&format!(
+            "    {}: {},\n",
+            format_property_name(&field.name),
+            field_schema
+        ));
+    }
+    output.push_str("})");
+    output
+}
+
+/// Formats an interface matching what zod infers for an object schema.
+fn format_interface(name: &str, fields: &[Field]) -> String {
+    let mut output = format!("export interface {} {{\n", name);
+    for field in fields {
+        output.push_str(&format_jsdoc(&field.description, "    "));
+
+        let optional = matches!(field.field_type, Type::Optional(_) | Type::Any);
+        output.push_str(&format!(
+            "    {}{}: {};\n",
+            format_property_name(&field.name),
+            if optional { "?" } else { "" },
+            format_type(&field.field_type)
+        ));
+    }
+    output.push_str("}\n\n");
+    output
+}
+
+fn format_inferred(name: &str) -> String {
+    format!("export type {} = z.infer<typeof {}Schema>;\n\n", name, name)
+}
+
+pub fn generate_zod(schema: &Schema) -> String {
+    let mut output = String::new();
+
+    output.push_str("// Generated by manyleb. Do not edit by hand.\n");
+    if let Some(title) = &schema.title {
+        output.push_str(&format!("// {}", title));
+        if let Some(version) = &schema.version {
+            output.push_str(&format!(" ({})", version));
+        }
+        output.push('\n');
+    }
+    output.push('\n');
+    output.push_str("import { z } from \"zod\";\n\n");
+
+    let recursive = recursive_objects(schema);
+    let mut defined = HashSet::new();
+
+    for object in &schema.objects {
+        let object_schema = format_object_schema(&object.fields, &defined, false);
+
+        if recursive.contains(object.id.as_str()) {
+            output.push_str(&format_interface(&object.id, &object.fields));
+            output.push_str(&format!(
+                "export const {}Schema: z.ZodType<{}> = {};\n\n",
+                object.id, object.id, object_schema
+            ));
+        } else {
+            output.push_str(&format!(
+                "export const {}Schema = {};\n",
+                object.id, object_schema
+            ));
+            output.push_str(&format_inferred(&object.id));
+        }
+
+        defined.insert(object.id.as_str());
+    }
+
+    if schema.endpoints.is_empty() {
+        return output;
+    }
+
+    output.push_str(RUNTIME);
+
+    for (endpoint, name) in schema
+        .endpoints
+        .iter()
+        .zip(endpoint_names(&schema.endpoints))
+    {
+        let mut doc = format!("{} {}", endpoint.method.to_uppercase(), endpoint.path);
+        if let Some(description) = &endpoint.description {
+            doc.push_str(&format!("\n\n{}", description));
+        }
+        output.push('\n');
+        output.push_str(&format_jsdoc(&doc, ""));
+
+        if !endpoint.params.is_empty() {
+            output.push_str(&format!(
+                "export const {}ParamsSchema = {};\n",
+                name,
+                format_object_schema(&endpoint.params, &defined, true)
+            ));
+            output.push_str(&format_inferred(&format!("{}Params", name)));
+        }
+
+        if !endpoint.query.is_empty() {
+            output.push_str(&format!(
+                "export const {}QuerySchema = {};\n",
+                name,
+                format_object_schema(&endpoint.query, &defined, true)
+            ));
+            output.push_str(&format_inferred(&format!("{}Query", name)));
+        }
+
+        if let Some(body_type) = &endpoint.request_body {
+            output.push_str(&format!(
+                "export const {}RequestBodySchema = {};\n",
+                name,
+                format_schema(body_type, &defined, false)
+            ));
+            output.push_str(&format_inferred(&format!("{}RequestBody", name)));
+        }
+
+        output.push_str(&format!("export const {}ResponseSchemas = {{\n", name));
+        for (status, response_type) in &endpoint.responses {
+            let response_schema = match response_type {
+                Some(t) => format_schema(t, &defined, false),
+                None => "z.undefined()".to_string(),
+            };
+            output.push_str(&format!("    {}: {},\n", status, response_schema));
+        }
+        output.push_str("};\n");
+        output.push_str(&format!(
+            "export type {}Response = ResponseOf<typeof {}ResponseSchemas>;\n",
+            name, name
+        ));
+    }
+
+    output
+}
@@ -0,0 +1,686 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Endpoint, Field, JsonValue, Object, Schema, Type};
+
+const STYLE: &str = r#"* {
+    box-sizing: border-box;
+}
+
+body {
+    margin: 0;
+    display: flex;
+    min-height: 100vh;
+    font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, Helvetica, Arial, sans-serif;
+    font-size: 15px;
+    line-height: 1.5;
+    color: #1f2328;
+    background: #ffffff;
+}
+
+a {
+    color: #0969da;
+    text-decoration: none;
+}
+
+a:hover {
+    text-decoration: underline;
+}
+
+code, pre {
+    font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace;
+    font-size: 13px;
+}
+
+pre {
+    padding: 12px 16px;
+    overflow-x: auto;
+    background: #f6f8fa;
+    border: 1px solid #d0d7de;
+    border-radius: 6px;
+}
+
+.sidebar {
+    position: sticky;
+    top: 0;
+    flex: 0 0 280px;
+    height: 100vh;
+    padding: 16px;
+    overflow-y: auto;
+    background: #f6f8fa;
+    border-right: 1px solid #d0d7de;
+}
+
+.sidebar .brand {
+    display: block;
+    margin-bottom: 12px;
+    font-size: 18px;
+    font-weight: 600;
+    color: #1f2328;
+}
+
+.sidebar h2 {
+    margin: 16px 0 4px;
+    font-size: 12px;
+    text-transform: uppercase;
+    letter-spacing: 0.05em;
+    color: #656d76;
+}
+
+.sidebar ul {
+    margin: 0;
+    padding: 0;
+    list-style: none;
+}
+
+.sidebar li a {
+    display: block;
+    padding: 2px 6px;
+    border-radius: 4px;
+    color: #1f2328;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+}
+
+.sidebar li a.active {
+    background: #ddf4ff;
+}
+
+#search {
+    width: 100%;
+    padding: 6px 8px;
+    font: inherit;
+    border: 1px solid #d0d7de;
+    border-radius: 6px;
+}
+
+#search-results li a {
+    white-space: normal;
+}
+
+#search-results .kind {
+    margin-left: 4px;
+    font-size: 12px;
+    color: #656d76;
+}
+
+main {
+    flex: 1;
+    min-width: 0;
+    max-width: 960px;
+    padding: 24px 40px;
+}
+
+h1 {
+    display: flex;
+    align-items: center;
+    gap: 12px;
+    margin-top: 0;
+    word-break: break-all;
+}
+
+table {
+    width: 100%;
+    margin-bottom: 16px;
+    border-collapse: collapse;
+}
+
+th, td {
+    padding: 6px 12px;
+    text-align: left;
+    vertical-align: top;
+    border: 1px solid #d0d7de;
+}
+
+th {
+    background: #f6f8fa;
+}
+
+.tag {
+    display: inline-block;
+    margin-right: 4px;
+    padding: 0 8px;
+    font-size: 12px;
+    border-radius: 12px;
+    background: #ddf4ff;
+}
+
+.method {
+    display: inline-block;
+    min-width: 56px;
+    padding: 0 6px;
+    font-size: 11px;
+    font-weight: 700;
+    text-align: center;
+    color: #ffffff;
+    border-radius: 4px;
+    background: #6e7781;
+}
+
+h1 .method {
+    font-size: 14px;
+    padding: 2px 8px;
+}
+
+.method.get {
+    background: #1a7f37;
+}
+
+.method.post {
+    background: #0969da;
+}
+
+.method.put {
+    background: #bc4c00;
+}
+
+.method.patch {
+    background: #1b7c83;
+}
+
+.method.delete {
+    background: #cf222e;
+}
+"#;
+
+const SEARCH: &str = r#"(function () {
+    var input = document.getElementById("search");
+    var results = document.getElementById("search-results");
+
+    input.addEventListener("input", function () {
+        var terms = input.value.toLowerCase().split(/\s+/).filter(Boolean);
+        results.innerHTML = "";
+        if (terms.length === 0) {
+            return;
+        }
+
+        var matches = SEARCH_INDEX.filter(function (entry) {
+            var text = (entry.title + " " + entry.text).toLowerCase();
+            return terms.every(function (term) {
+                return text.indexOf(term) !== -1;
+            });
+        });
+
+        matches.slice(0, 20).forEach(function (entry) {
+            var item = document.createElement("li");
+            var link = document.createElement("a");
+            var kind = document.createElement("span");
+            link.href = entry.url;
+            link.textContent = entry.title;
+            kind.className = "kind";
+            kind.textContent = entry.kind;
+            link.appendChild(kind);
+            item.appendChild(link);
+            results.appendChild(item);
+        });
+
+        if (matches.length === 0) {
+            var empty = document.createElement("li");
+            empty.textContent = "No results";
+            results.appendChild(empty);
+        }
+    });
+})();
+"#;
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Formats a description as paragraphs separated by blank lines.
+fn format_description(description: &str) -> String {
+    description
+        .split("\n\n")
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| format!("<p>{}</p>\n", escape(p.trim()).replace('\n', "<br>\n")))
+        .collect()
+}
+
+fn format_example(example: &JsonValue) -> String {
+    format!(
+        "<pre><code>{}</code></pre>\n",
+        escape(&example.to_pretty_json(0))
+    )
+}
+
+/// Assigns a unique file name to every object and endpoint page.
+struct Pages {
+    objects: HashMap<String, String>,
+    endpoints: Vec<String>,
+}
+
+impl Pages {
+    fn new(schema: &Schema) -> Pages {
+        let mut used = HashSet::new();
+        let mut unique = |base: String| {
+            let mut name = base.clone();
+            let mut n = 1;
+            while !used.insert(name.clone()) {
+                n += 1;
+                name = format!("{}-{}", base, n);
+            }
+            format!("{}.html", name)
+        };
+
+        let slug = |s: &str| {
+            s.to_lowercase()
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|w| !w.is_empty())
+                .collect::<Vec<_>>()
+                .join("-")
+        };
+
+        let objects = schema
+            .objects
+            .iter()
+            .map(|o| (o.id.clone(), unique(format!("object-{}", slug(&o.id)))))
+            .collect();
+        let endpoints = schema
+            .endpoints
+            .iter()
+            .map(|e| unique(slug(&format!("endpoint {} {}", e.method, e.path))))
+            .collect();
+
+        Pages { objects, endpoints }
+    }
+}
+
+fn format_type(t: &Type, pages: &Pages) -> String {
+    match t {
+        Type::Any => "any".to_string(),
+        Type::Null => "null".to_string(),
+        Type::String => "string".to_string(),
+        Type::Integer => "integer".to_string(),
+        Type::Float => "float".to_string(),
+        Type::Boolean => "boolean".to_string(),
+        Type::Reference(name) => match pages.objects.get(name) {
+            Some(page) => format!("<a href=\"{}\">{}</a>", page, escape(name)),
+            None => escape(name),
+        },
+        Type::Array(item_type) => format!("[]{}", format_type(item_type, pages)),
+        Type::Mapping(key_type, value_type) => format!(
+            "&lt;{}, {}&gt;",
+            format_type(key_type, pages),
+            format_type(value_type, pages)
+        ),
+        Type::Optional(inner_type) => format!("?{}", format_type(inner_type, pages)),
+    }
+}
+
+fn format_field_table(name_header: &str, fields: &[Field], pages: &Pages) -> String {
+    let has_examples = fields.iter().any(|f| f.example.is_some());
+
+    let mut table = String::from("<table>\n<thead>\n<tr>");
+    table.push_str(&format!(
+        "<th>{}</th><th>Type</th><th>Description</th>",
+        name_header
+    ));
+    if has_examples {
+        table.push_str("<th>Example</th>");
+    }
+    table.push_str("</tr>\n</thead>\n<tbody>\n");
+
+    for field in fields {
+        table.push_str(&format!(
+            "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td>",
+            escape(&field.name),
+            format_type(&field.field_type, pages),
+            escape(&field.description).replace('\n', "<br>")
+        ));
+        if has_examples {
+            match &field.example {
+                Some(example) => table.push_str(&format!(
+                    "<td><code>{}</code></td>",
+                    escape(&example.to_json())
+                )),
+                None => table.push_str("<td></td>"),
+            }
+        }
+        table.push_str("</tr>\n");
+    }
+
+    table.push_str("</tbody>\n</table>\n");
+    table
+}
+
+fn method_badge(method: &str) -> String {
+    format!(
+        "<span class=\"method {}\">{}</span>",
+        escape(&method.to_lowercase()),
+        escape(&method.to_uppercase())
+    )
+}
+
+fn format_sidebar(schema: &Schema, pages: &Pages, current: &str) -> String {
+    let link = |page: &str, label: String| {
+        let class = if page == current {
+            " class=\"active\""
+        } else {
+            ""
+        };
+        format!("<li><a href=\"{}\"{}>{}</a></li>\n", page, class, label)
+    };
+    let endpoint_link = |i: usize, endpoint: &Endpoint| {
+        link(
+            &pages.endpoints[i],
+            format!(
+                "{} {}",
+                method_badge(&endpoint.method),
+                escape(&endpoint.path)
+            ),
+        )
+    };
+
+    let mut sidebar = String::from("<nav class=\"sidebar\">\n");
+    sidebar.push_str(&format!(
+        "<a class=\"brand\" href=\"index.html\">{}</a>\n",
+        escape(schema.title.as_deref().unwrap_or("API Documentation"))
+    ));
+    sidebar.push_str(
+        "<input id=\"search\" type=\"search\" placeholder=\"Search\" autocomplete=\"off\">\n",
+    );
+    sidebar.push_str("<ul id=\"search-results\"></ul>\n");
+
+    let mut tags: Vec<&str> = Vec::new();
+    for endpoint in &schema.endpoints {
+        for tag in &endpoint.tags {
+            if !tags.contains(&tag.as_str()) {
+                tags.push(tag);
+            }
+        }
+    }
+
+    for tag in tags {
+        sidebar.push_str(&format!("<h2>{}</h2>\n<ul>\n", escape(tag)));
+        for (i, endpoint) in schema.endpoints.iter().enumerate() {
+            if endpoint.tags.iter().any(|t| t == tag) {
+                sidebar.push_str(&endpoint_link(i, endpoint));
+            }
+        }
+        sidebar.push_str("</ul>\n");
+    }
+
+    if schema.endpoints.iter().any(|e| e.tags.is_empty()) {
+        let heading = if schema.endpoints.iter().all(|e| e.tags.is_empty()) {
+            "Endpoints"
+        } else {
+            "Other"
+        };
+        sidebar.push_str(&format!("<h2>{}</h2>\n<ul>\n", heading));
+        for (i, endpoint) in schema.endpoints.iter().enumerate() {
+            if endpoint.tags.is_empty() {
+                sidebar.push_str(&endpoint_link(i, endpoint));
+            }
+        }
+        sidebar.push_str("</ul>\n");
+    }
+
+    if !schema.objects.is_empty() {
+        sidebar.push_str("<h2>Objects</h2>\n<ul>\n");
+        for object in &schema.objects {
+            sidebar.push_str(&link(&pages.objects[&object.id], escape(&object.id)));
+        }
+        sidebar.push_str("</ul>\n");
+    }
+
+    sidebar.push_str("</nav>\n");
+    sidebar
+}
+
+fn format_page(
+    schema: &Schema,
+    pages: &Pages,
+    current: &str,
+    title: &str,
+    content: &str,
+) -> String {
+    let site_title = schema.title.as_deref().unwrap_or("API Documentation");
+    let page_title = if title == site_title {
+        escape(title)
+    } else {
+        format!("{} - {}", escape(title), escape(site_title))
+    };
+
+    let mut page = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    page.push_str("<meta charset=\"utf-8\">\n");
+    page.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    page.push_str(&format!("<title>{}</title>\n", page_title));
+    page.push_str("<link rel=\"stylesheet\" href=\"style.css\">\n");
+    page.push_str("</head>\n<body>\n");
+    page.push_str(&format_sidebar(schema, pages, current));
+    page.push_str("<main>\n");
+    page.push_str(content);
+    page.push_str("</main>\n");
+    page.push_str("<script src=\"search.js\"></script>\n");
+    page.push_str("</body>\n</html>\n");
+    page
+}
+
+fn format_index(schema: &Schema, pages: &Pages) -> String {
+    let mut content = format!(
+        "<h1>{}</h1>\n",
+        escape(schema.title.as_deref().unwrap_or("API Documentation"))
+    );
+    if let Some(version) = &schema.version {
+        content.push_str(&format!(
+            "<p><strong>Version:</strong> {}</p>\n",
+            escape(version)
+        ));
+    }
+    if let Some(description) = &schema.description {
+        content.push_str(&format_description(description));
+    }
+
+    if !schema.endpoints.is_empty() {
+        content.push_str("<h2>Endpoints</h2>\n<table>\n<tbody>\n");
+        for (i, endpoint) in schema.endpoints.iter().enumerate() {
+            content.push_str(&format!(
+                "<tr><td>{} <a href=\"{}\"><code>{}</code></a></td><td>{}</td></tr>\n",
+                method_badge(&endpoint.method),
+                pages.endpoints[i],
+                escape(&endpoint.path),
+                escape(endpoint.description.as_deref().unwrap_or_default())
+            ));
+        }
+        content.push_str("</tbody>\n</table>\n");
+    }
+
+    if !schema.objects.is_empty() {
+        content.push_str("<h2>Objects</h2>\n<ul>\n");
+        for object in &schema.objects {
+            content.push_str(&format!(
+                "<li><a href=\"{}\"><code>{}</code></a></li>\n",
+                pages.objects[&object.id],
+                escape(&object.id)
+            ));
+        }
+        content.push_str("</ul>\n");
+    }
+
+    content
+}
+
+fn format_object(object: &Object, pages: &Pages) -> String {
+    let mut content = format!("<h1>{}</h1>\n", escape(&object.id));
+
+    if object.fields.is_empty() {
+        content.push_str("<p>This object has no properties.</p>\n");
+    } else {
+        content.push_str(&format_field_table("Property", &object.fields, pages));
+    }
+
+    if let Some(example) = &object.example {
+        content.push_str("<h2>Example</h2>\n");
+        content.push_str(&format_example(example));
+    }
+
+    content
+}
+
+fn format_endpoint(endpoint: &Endpoint, pages: &Pages) -> String {
+    let mut content = format!(
+        "<h1>{} <code>{}</code></h1>\n",
+        method_badge(&endpoint.method),
+        escape(&endpoint.path)
+    );
+
+    if !endpoint.tags.is_empty() {
+        content.push_str("<p>");
+        for tag in &endpoint.tags {
+            content.push_str(&format!("<span class=\"tag\">{}</span>", escape(tag)));
+        }
+        content.push_str("</p>\n");
+    }
+
+    if let Some(description) = &endpoint.description {
+        content.push_str(&format_description(description));
+    }
+
+    if !endpoint.params.is_empty() {
+        content.push_str("<h2>Parameters</h2>\n");
+        content.push_str(&format_field_table("Name", &endpoint.params, pages));
+    }
+
+    if !endpoint.query.is_empty() {
+        content.push_str("<h2>Query Parameters</h2>\n");
+        content.push_str(&format_field_table("Name", &endpoint.query, pages));
+    }
+
+    if let Some(body_type) = &endpoint.request_body {
+        content.push_str("<h2>Request Body</h2>\n");
+        content.push_str(&format!(
+            "<p><code>{}</code></p>\n",
+            format_type(body_type, pages)
+        ));
+        if let Some(example) = &endpoint.request_body_example {
+            content.push_str(&format_example(example));
+        }
+    }
+
+    content.push_str("<h2>Responses</h2>\n<table>\n<thead>\n");
+    content.push_str("<tr><th>Status Code</th><th>Type</th></tr>\n</thead>\n<tbody>\n");
+    for (status, response_type) in &endpoint.responses {
+        let response_type = match response_type {
+            Some(t) => format!("<code>{}</code>", format_type(t, pages)),
+            None => "No Content".to_string(),
+        };
+        content.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td></tr>\n",
+            status, response_type
+        ));
+    }
+    content.push_str("</tbody>\n</table>\n");
+
+    for (status, example) in &endpoint.response_examples {
+        content.push_str(&format!("<h3>Example {} Response</h3>\n", status));
+        content.push_str(&format_example(example));
+    }
+
+    content
+}
+
+fn format_search_index(schema: &Schema, pages: &Pages) -> String {
+    let entry = |title: String, kind: &str, url: &str, text: String| {
+        JsonValue::Object(vec![
+            ("title".to_string(), JsonValue::String(title)),
+            ("kind".to_string(), JsonValue::String(kind.to_string())),
+            ("url".to_string(), JsonValue::String(url.to_string())),
+            ("text".to_string(), JsonValue::String(text)),
+        ])
+    };
+
+    let mut entries = Vec::new();
+    for (i, endpoint) in schema.endpoints.iter().enumerate() {
+        let mut text = vec![endpoint.description.clone().unwrap_or_default()];
+        text.extend(endpoint.tags.iter().cloned());
+        text.extend(
+            endpoint
+                .params
+                .iter()
+                .chain(&endpoint.query)
+                .map(|f| f.name.clone()),
+        );
+
+        entries.push(entry(
+            format!("{} {}", endpoint.method.to_uppercase(), endpoint.path),
+            "endpoint",
+            &pages.endpoints[i],
+            text.join(" "),
+        ));
+    }
+    for object in &schema.objects {
+        let text = object
+            .fields
+            .iter()
+            .map(|f| format!("{} {}", f.name, f.description))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        entries.push(entry(
+            object.id.clone(),
+            "object",
+            &pages.objects[&object.id],
+            text,
+        ));
+    }
+
+    // Escape "</" so the index cannot close the script element it is loaded in.
+    let index = JsonValue::Array(entries).to_json().replace("</", "<\\/");
+    format!("var SEARCH_INDEX = {};\n\n{}", index, SEARCH)
+}
+
+/// Generates a static documentation site as a list of file names and their
+/// contents. Every page links only to the other generated files.
+pub fn generate_html(schema: &Schema) -> Vec<(String, String)> {
+    let pages = Pages::new(schema);
+    let mut files = Vec::new();
+
+    files.push((
+        "index.html".to_string(),
+        format_page(
+            schema,
+            &pages,
+            "index.html",
+            schema.title.as_deref().unwrap_or("API Documentation"),
+            &format_index(schema, &pages),
+        ),
+    ));
+
+    for object in &schema.objects {
+        let page = &pages.objects[&object.id];
+        files.push((
+            page.clone(),
+            format_page(
+                schema,
+                &pages,
+                page,
+                &object.id,
+                &format_object(object, &pages),
+            ),
+        ));
+    }
+
+    for (i, endpoint) in schema.endpoints.iter().enumerate() {
+        let page = &pages.endpoints[i];
+        let title = format!("{} {}", endpoint.method.to_uppercase(), endpoint.path);
+        files.push((
+            page.clone(),
+            format_page(
+                schema,
+                &pages,
+                page,
+                &title,
+                &format_endpoint(endpoint, &pages),
+            ),
+        ));
+    }
+
+    files.push(("style.css".to_string(), STYLE.to_string()));
+    files.push(("search.js".to_string(), format_search_index(schema, &pages)));
+
+    files
+}
@@ -0,0 +1,462 @@
+use crate::parser::read_unicode_escape;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+pub fn escape_json_string(s: &str) -> String {
+    let mut result = String::new();
+
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Formats a float so that it reads back as a float, using exponent notation
+/// for very large or small magnitudes rather than writing out every digit.
+fn format_float(f: f64) -> String {
+    if !f.is_finite() {
+        "null".to_string()
+    } else if f != 0.0 && (f.abs() >= 1e16 || f.abs() < 1e-5) {
+        format!("{:e}", f)
+    } else if f.fract() == 0.0 {
+        format!("{:.1}", f)
+    } else {
+        format!("{}", f)
+    }
+}
+
+impl JsonValue {
+    pub fn parse(input: &str) -> Result<JsonValue, String> {
+        let mut parser = JsonParser {
+            chars: input.chars().collect(),
+            position: 0,
+        };
+
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+
+        if parser.position < parser.chars.len() {
+            return Err(parser.error_message("Unexpected trailing characters"));
+        }
+
+        Ok(value)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        match self {
+            JsonValue::Null => "null".to_string(),
+            JsonValue::Boolean(b) => b.to_string(),
+            JsonValue::Integer(i) => i.to_string(),
+            JsonValue::Float(f) => format_float(*f),
+            JsonValue::String(s) => format!("\"{}\"", escape_json_string(s)),
+            JsonValue::Array(items) => {
+                let items: Vec<String> = items.iter().map(|i| i.to_json()).collect();
+                format!("[{}]", items.join(","))
+            }
+            JsonValue::Object(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(k, v)| format!("\"{}\":{}", escape_json_string(k), v.to_json()))
+                    .collect();
+                format!("{{{}}}", entries.join(","))
+            }
+        }
+    }
+
+    pub fn to_pretty_json(&self, indent: usize) -> String {
+        let pad = "    ".repeat(indent + 1);
+        let end_pad = "    ".repeat(indent);
+
+        match self {
+            JsonValue::Array(items) if !items.is_empty() => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|i| format!("{}{}", pad, i.to_pretty_json(indent + 1)))
+                    .collect();
+                format!("[\n{}\n{}]", items.join(",\n"), end_pad)
+            }
+            JsonValue::Object(entries) if !entries.is_empty() => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(k, v)| {
+                        format!(
+                            "{}\"{}\": {}",
+                            pad,
+                            escape_json_string(k),
+                            v.to_pretty_json(indent + 1)
+                        )
+                    })
+                    .collect();
+                format!("{{\n{}\n{}}}", entries.join(",\n"), end_pad)
+            }
+            _ => self.to_json(),
+        }
+    }
+}
+
+struct JsonParser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl JsonParser {
+    fn error_message(&self, message: &str) -> String {
+        format!("Invalid JSON at offset {}: {}", self.position, message)
+    }
+
+    /// Skips a run of digits, returning how many were skipped.
+    fn skip_digits(&mut self) -> usize {
+        let start = self.position;
+        while self
+            .chars
+            .get(self.position)
+            .is_some_and(|c| c.is_ascii_digit())
+        {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    /// Reports the malformed number starting at `start`, including the rest of
+    /// the characters that look like part of it.
+    fn invalid_number(&self, start: usize) -> String {
+        let number: String = self.chars[start..]
+            .iter()
+            .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'))
+            .collect();
+        self.error_message(&format!("Invalid number '{}'", number))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.chars.get(self.position) {
+            if c.is_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+        for expected in literal.chars() {
+            if self.chars.get(self.position) != Some(&expected) {
+                return Err(self.error_message(&format!("Expected '{}'", literal)));
+            }
+            self.position += 1;
+        }
+
+        Ok(value)
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+
+        match self.chars.get(self.position) {
+            Some('n') => self.expect_literal("null", JsonValue::Null),
+            Some('t') => self.expect_literal("true", JsonValue::Boolean(true)),
+            Some('f') => self.expect_literal("false", JsonValue::Boolean(false)),
+            Some('"') => Ok(JsonValue::String(self.parse_string()?)),
+            Some('[') => self.parse_array(),
+            Some('{') => self.parse_object(),
+            Some(c) if *c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(c) => Err(self.error_message(&format!("Unexpected character '{}'", c))),
+            None => Err(self.error_message("Unexpected end of input")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        let mut result = String::new();
+        self.position += 1;
+
+        while let Some(c) = self.chars.get(self.position) {
+            self.position += 1;
+
+            match c {
+                '"' => return Ok(result),
+                '\\' => {
+                    let escaped = self
+                        .chars
+                        .get(self.position)
+                        .copied()
+                        .ok_or_else(|| self.error_message("Unterminated escape sequence"))?;
+                    self.position += 1;
+
+                    match escaped {
+                        '"' => result.push('"'),
+                        '\\' => result.push('\\'),
+                        '/' => result.push('/'),
+                        'b' => result.push('\u{08}'),
+                        'f' => result.push('\u{0c}'),
+                        'n' => result.push('\n'),
+                        'r' => result.push('\r'),
+                        't' => result.push('\t'),
+                        'u' => {
+                            let mut chars = self.chars[self.position..].iter().copied();
+                            let decoded = read_unicode_escape(&mut chars)
+                                .map_err(|message| self.error_message(&message))?;
+                            self.position = self.chars.len() - chars.len();
+                            result.push(decoded);
+                        }
+                        other => {
+                            return Err(
+                                self.error_message(&format!("Invalid escape sequence \\{}", other))
+                            );
+                        }
+                    }
+                }
+                c => result.push(*c),
+            }
+        }
+
+        Err(self.error_message("Unterminated string"))
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, String> {
+        let start = self.position;
+        let mut is_float = false;
+
+        if self.chars.get(self.position) == Some(&'-') {
+            self.position += 1;
+        }
+
+        let leading_zero = self.chars.get(self.position) == Some(&'0');
+        let digits = self.skip_digits();
+        if digits == 0 || (leading_zero && digits > 1) {
+            return Err(self.invalid_number(start));
+        }
+
+        if self.chars.get(self.position) == Some(&'.') {
+            is_float = true;
+            self.position += 1;
+            if self.skip_digits() == 0 {
+                return Err(self.invalid_number(start));
+            }
+        }
+
+        if matches!(self.chars.get(self.position), Some('e' | 'E')) {
+            is_float = true;
+            self.position += 1;
+            if matches!(self.chars.get(self.position), Some('+' | '-')) {
+                self.position += 1;
+            }
+            if self.skip_digits() == 0 {
+                return Err(self.invalid_number(start));
+            }
+        }
+
+        let number: String = self.chars[start..self.position].iter().collect();
+
+        if is_float {
+            number
+                .parse::<f64>()
+                .map(JsonValue::Float)
+                .map_err(|_| self.invalid_number(start))
+        } else {
+            number
+                .parse::<i64>()
+                .map(JsonValue::Integer)
+                .map_err(|_| self.error_message(&format!("Invalid number '{}'", number)))
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, String> {
+        let mut items = Vec::new();
+        self.position += 1;
+        self.skip_whitespace();
+
+        if self.chars.get(self.position) == Some(&']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(items));
+        }
+
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+
+            match self.chars.get(self.position) {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Array(items));
+                }
+                _ => return Err(self.error_message("Expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, String> {
+        let mut entries = Vec::new();
+        self.position += 1;
+        self.skip_whitespace();
+
+        if self.chars.get(self.position) == Some(&'}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.chars.get(self.position) != Some(&'"') {
+                return Err(self.error_message("Expected string key"));
+            }
+            let key = self.parse_string()?;
+
+            self.skip_whitespace();
+            if self.chars.get(self.position) != Some(&':') {
+                return Err(self.error_message("Expected ':'"));
+            }
+            self.position += 1;
+
+            let value = self.parse_value()?;
+            entries.push((key, value));
+            self.skip_whitespace();
+
+            match self.chars.get(self.position) {
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Object(entries));
+                }
+                _ => return Err(self.error_message("Expected ',' or '}'")),
+            }
+        }
+    }
+}
+
+/// Examples are serialised as the JSON they hold, keeping the order of keys.
+#[cfg(feature = "serde")]
+impl serde::Serialize for JsonValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{SerializeMap, SerializeSeq};
+
+        match self {
+            JsonValue::Null => serializer.serialize_unit(),
+            JsonValue::Boolean(b) => serializer.serialize_bool(*b),
+            JsonValue::Integer(i) => serializer.serialize_i64(*i),
+            JsonValue::Float(f) => serializer.serialize_f64(*f),
+            JsonValue::String(s) => serializer.serialize_str(s),
+            JsonValue::Array(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            JsonValue::Object(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct JsonValueVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for JsonValueVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_unit<E>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, d: D) -> Result<JsonValue, D::Error> {
+        serde::Deserialize::deserialize(d)
+    }
+
+    fn visit_bool<E>(self, b: bool) -> Result<JsonValue, E> {
+        Ok(JsonValue::Boolean(b))
+    }
+
+    fn visit_i64<E>(self, i: i64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Integer(i))
+    }
+
+    fn visit_u64<E>(self, u: u64) -> Result<JsonValue, E> {
+        Ok(match i64::try_from(u) {
+            Ok(i) => JsonValue::Integer(i),
+            Err(_) => JsonValue::Float(u as f64),
+        })
+    }
+
+    fn visit_f64<E>(self, f: f64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Float(f))
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(s.to_string()))
+    }
+
+    fn visit_string<E>(self, s: String) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(s))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<JsonValue, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(JsonValue::Array(items))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<JsonValue, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(JsonValue::Object(entries))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for JsonValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonValueVisitor)
+    }
+}
+
+/// Deserializes a present example, including `null`, as `Some`. Missing
+/// examples fall back to `None` through `#[serde(default)]`.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_example<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<JsonValue>, D::Error> {
+    serde::Deserialize::deserialize(deserializer).map(Some)
+}
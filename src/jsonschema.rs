@@ -0,0 +1,163 @@
+use crate::{JsonValue, Object, Schema, Type};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+fn string(s: &str) -> JsonValue {
+    JsonValue::String(s.to_string())
+}
+
+fn type_schema(name: &str) -> JsonValue {
+    JsonValue::Object(vec![("type".to_string(), string(name))])
+}
+
+fn format_type(
+    t: &Type,
+    reference: &dyn Fn(&str) -> String,
+    context: &str,
+) -> Result<JsonValue, String> {
+    Ok(match t {
+        Type::Any => JsonValue::Object(Vec::new()),
+        Type::Null => type_schema("null"),
+        Type::String => type_schema("string"),
+        Type::Integer => type_schema("integer"),
+        Type::Float => type_schema("number"),
+        Type::Boolean => type_schema("boolean"),
+        Type::Reference(name) => {
+            JsonValue::Object(vec![("$ref".to_string(), string(&reference(name)))])
+        }
+        Type::Array(item_type) => JsonValue::Object(vec![
+            ("type".to_string(), string("array")),
+            (
+                "items".to_string(),
+                format_type(item_type, reference, context)?,
+            ),
+        ]),
+        Type::Mapping(key_type, value_type) => {
+            if !matches!(key_type.as_ref(), Type::String) {
+                return Err(format!(
+                    "Mapping key type '{}' in {} is not supported by JSON Schema, only string keys are allowed",
+                    key_type, context
+                ));
+            }
+
+            JsonValue::Object(vec![
+                ("type".to_string(), string("object")),
+                (
+                    "additionalProperties".to_string(),
+                    format_type(value_type, reference, context)?,
+                ),
+            ])
+        }
+        Type::Optional(inner_type) => {
+            let inner = format_type(inner_type, reference, context)?;
+
+            match inner {
+                JsonValue::Object(entries)
+                    if entries.len() == 1
+                        && entries[0].0 == "type"
+                        && matches!(entries[0].1, JsonValue::String(_)) =>
+                {
+                    JsonValue::Object(vec![(
+                        "type".to_string(),
+                        JsonValue::Array(vec![entries[0].1.clone(), string("null")]),
+                    )])
+                }
+                inner => JsonValue::Object(vec![(
+                    "anyOf".to_string(),
+                    JsonValue::Array(vec![inner, type_schema("null")]),
+                )]),
+            }
+        }
+    })
+}
+
+fn object_schema(
+    object: &Object,
+    reference: &dyn Fn(&str) -> String,
+) -> Result<Vec<(String, JsonValue)>, String> {
+    let mut properties = Vec::new();
+    let mut required = Vec::new();
+
+    for field in &object.fields {
+        let context = format!("field '{}' of object '{}'", field.name, object.id);
+        let mut property = match format_type(&field.field_type, reference, &context)? {
+            JsonValue::Object(entries) => entries,
+            _ => unreachable!(),
+        };
+
+        if !field.description.is_empty() {
+            property.push(("description".to_string(), string(&field.description)));
+        }
+        if let Some(example) = &field.example {
+            property.push((
+                "examples".to_string(),
+                JsonValue::Array(vec![example.clone()]),
+            ));
+        }
+
+        if !matches!(field.field_type, Type::Optional(_)) {
+            required.push(string(&field.name));
+        }
+        properties.push((field.name.clone(), JsonValue::Object(property)));
+    }
+
+    let mut entries = vec![
+        ("title".to_string(), string(&object.id)),
+        ("type".to_string(), string("object")),
+        ("properties".to_string(), JsonValue::Object(properties)),
+    ];
+    if !required.is_empty() {
+        entries.push(("required".to_string(), JsonValue::Array(required)));
+    }
+    if let Some(example) = &object.example {
+        entries.push((
+            "examples".to_string(),
+            JsonValue::Array(vec![example.clone()]),
+        ));
+    }
+
+    Ok(entries)
+}
+
+/// Generates one schema document per object, keyed by file name. References
+/// between objects point at the sibling document's file name.
+pub fn generate_json_schemas(schema: &Schema) -> Result<Vec<(String, JsonValue)>, String> {
+    let reference = |name: &str| format!("{}.schema.json", name);
+    let mut documents = Vec::new();
+
+    for object in &schema.objects {
+        let mut entries = vec![
+            ("$schema".to_string(), string(DRAFT)),
+            ("$id".to_string(), string(&reference(&object.id))),
+        ];
+        entries.extend(object_schema(object, &reference)?);
+
+        documents.push((reference(&object.id), JsonValue::Object(entries)));
+    }
+
+    Ok(documents)
+}
+
+/// Generates a single schema document with every object under `$defs`.
+pub fn generate_json_schema_bundle(schema: &Schema) -> Result<JsonValue, String> {
+    let reference = |name: &str| format!("#/$defs/{}", name);
+    let mut definitions = Vec::new();
+
+    for object in &schema.objects {
+        definitions.push((
+            object.id.clone(),
+            JsonValue::Object(object_schema(object, &reference)?),
+        ));
+    }
+
+    let mut entries = vec![("$schema".to_string(), string(DRAFT))];
+    if let Some(title) = &schema.title {
+        entries.push(("title".to_string(), string(title)));
+    }
+    if let Some(description) = &schema.description {
+        entries.push(("description".to_string(), string(description)));
+    }
+    entries.push(("$defs".to_string(), JsonValue::Object(definitions)));
+
+    Ok(JsonValue::Object(entries))
+}
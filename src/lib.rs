@@ -1,9 +1,37 @@
+mod changelog;
+mod diagram;
+mod diff;
 mod docs;
+mod example;
 mod formatter;
+mod generators;
+mod html;
+mod json;
+mod jsonschema;
+mod mock;
 mod parser;
+mod postman;
 mod schema;
+mod summary;
+mod version;
 
-pub use docs::{generate_docs, generate_summary};
+pub use changelog::{generate_changelog, generate_changelog_with_options};
+pub use diagram::{DiagramFormat, generate_diagram};
+pub use diff::{Change, ChangeKind, ChangeTarget, DiffFormat, diff_schemas, format_diff};
+pub use docs::{DocsOptions, generate_docs, generate_docs_with_options};
+pub use example::{ExampleOptions, generate_example};
 pub use formatter::format;
+pub use generators::{
+    ProtoLock, PythonStyle, generate_go, generate_go_client, generate_kotlin, generate_proto,
+    generate_python, generate_rust, generate_rust_client, generate_rust_server, generate_swift,
+    generate_typescript, generate_typescript_client, generate_zod,
+};
+pub use html::generate_html;
+pub use json::JsonValue;
+pub use jsonschema::{generate_json_schema_bundle, generate_json_schemas};
+pub use mock::serve_mock;
 pub use parser::parse;
+pub use postman::generate_postman;
 pub use schema::*;
+pub use summary::{SummaryFormat, SummaryOptions, generate_summary, generate_summary_with_options};
+pub use version::{Bump, check_version, required_bump};
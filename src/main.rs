@@ -1,5 +1,8 @@
-use manyleb::{format as format_schema, parse};
-
+use manyleb::{
+    Bump, ChangeKind, DiagramFormat, DiffFormat, DocsOptions, ExampleOptions, ProtoLock,
+    PythonStyle, SummaryFormat, SummaryOptions, Type, format as format_schema, generate_example,
+    parse,
+};
 
 fn format(file: &String) -> Result<(), String> {
     let input = std::fs::read_to_string(file).unwrap();
@@ -18,29 +21,255 @@ fn verify(file: &String) -> Result<(), String> {
     schema.verify()
 }
 
-fn docs(input_file: &String, output_file: &String) -> Result<(), String> {
+fn docs(input_file: &String, output_file: &String, options: &DocsOptions) -> Result<(), String> {
     let input = std::fs::read_to_string(input_file).unwrap();
     let schema = parse(input.as_str(), true)?;
 
-    let docs = manyleb::generate_docs(&schema);
+    let docs = manyleb::generate_docs_with_options(&schema, options);
     std::fs::write(output_file, docs).unwrap();
 
     Ok(())
 }
 
-fn summary(input_file: &String, output_file: &String) -> Result<(), String> {
+fn docs_html(input_file: &String, output_dir: &String) -> Result<(), String> {
     let input = std::fs::read_to_string(input_file).unwrap();
     let schema = parse(input.as_str(), true)?;
+    schema.verify()?;
+
+    std::fs::create_dir_all(output_dir).map_err(|e| e.to_string())?;
+    let output_dir = std::path::Path::new(output_dir);
+
+    for (file_name, contents) in manyleb::generate_html(&schema) {
+        std::fs::write(output_dir.join(file_name), contents).unwrap();
+    }
+
+    Ok(())
+}
 
-    let summary = manyleb::generate_summary(&schema);
+fn summary(input_file: &String, output_file: &String, format: SummaryFormat) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema = parse(input.as_str(), true)?;
+
+    let summary = manyleb::generate_summary_with_options(&schema, &SummaryOptions { format });
     std::fs::write(output_file, summary).unwrap();
 
     Ok(())
 }
 
+fn mock(input_file: &String, port: u16) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema = parse(input.as_str(), true)?;
+    schema.verify()?;
+
+    manyleb::serve_mock(&schema, port)
+}
+
+fn example(input_file: &String, object: &str, options: &ExampleOptions) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema = parse(input.as_str(), true)?;
+    schema.verify()?;
+
+    if schema.get_object(object).is_none() {
+        return Err(format!("Undefined object '{}'", object));
+    }
+
+    let example = generate_example(&schema, &Type::Reference(object.to_string()), options);
+    println!("{}", example.to_pretty_json(0));
+
+    Ok(())
+}
+
+fn generate(target: &str, input_file: &String, output_file: &String) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema = parse(input.as_str(), true)?;
+    schema.verify()?;
+
+    let output = match target {
+        "go" => manyleb::generate_go(&schema),
+        "go-client" => manyleb::generate_go_client(&schema),
+        "kotlin" | "kt" => manyleb::generate_kotlin(&schema),
+        "proto" => {
+            let lock_file = format!("{}.lock", output_file);
+            let mut lock = match std::fs::read_to_string(&lock_file) {
+                Ok(input) => ProtoLock::parse(&input)?,
+                Err(_) => ProtoLock::default(),
+            };
+
+            let output = manyleb::generate_proto(&schema, &mut lock)?;
+            std::fs::write(&lock_file, lock.to_string()).unwrap();
+            output
+        }
+        "python" | "py" => manyleb::generate_python(&schema, PythonStyle::Pydantic),
+        "python-dataclasses" | "py-dataclasses" => {
+            manyleb::generate_python(&schema, PythonStyle::Dataclasses)
+        }
+        "rust" | "rs" => manyleb::generate_rust(&schema),
+        "rust-server" | "rs-server" => manyleb::generate_rust_server(&schema),
+        "rust-client" | "rs-client" => manyleb::generate_rust_client(&schema),
+        "swift" => manyleb::generate_swift(&schema),
+        "typescript" | "ts" => manyleb::generate_typescript(&schema),
+        "typescript-client" | "ts-client" => manyleb::generate_typescript_client(&schema),
+        "zod" => manyleb::generate_zod(&schema),
+        _ => return Err(format!("Unknown generator target '{}'", target)),
+    };
+    std::fs::write(output_file, output).unwrap();
+
+    Ok(())
+}
+
+fn jsonschema(input_file: &String, output_dir: &String, bundle: bool) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema = parse(input.as_str(), true)?;
+    schema.verify()?;
+
+    std::fs::create_dir_all(output_dir).map_err(|e| e.to_string())?;
+    let output_dir = std::path::Path::new(output_dir);
+
+    if bundle {
+        let document = manyleb::generate_json_schema_bundle(&schema)?;
+        std::fs::write(
+            output_dir.join("schema.json"),
+            format!("{}\n", document.to_pretty_json(0)),
+        )
+        .unwrap();
+    } else {
+        for (file_name, document) in manyleb::generate_json_schemas(&schema)? {
+            std::fs::write(
+                output_dir.join(file_name),
+                format!("{}\n", document.to_pretty_json(0)),
+            )
+            .unwrap();
+        }
+    }
+
+    Ok(())
+}
+
+fn diff(old_file: &String, new_file: &String, format: DiffFormat) -> Result<bool, String> {
+    let old_input = std::fs::read_to_string(old_file).unwrap();
+    let old_schema = parse(old_input.as_str(), true)?;
+    old_schema.verify()?;
+
+    let new_input = std::fs::read_to_string(new_file).unwrap();
+    let new_schema = parse(new_input.as_str(), true)?;
+    new_schema.verify()?;
+
+    let changes = manyleb::diff_schemas(&old_schema, &new_schema);
+    print!("{}", manyleb::format_diff(&changes, format));
+
+    Ok(changes.iter().any(|c| c.kind == ChangeKind::Breaking))
+}
+
+fn version_check(old_file: &String, new_file: &String) -> Result<(), String> {
+    let old_input = std::fs::read_to_string(old_file).unwrap();
+    let old_schema = parse(old_input.as_str(), true)?;
+    old_schema.verify()?;
+
+    let new_input = std::fs::read_to_string(new_file).unwrap();
+    let new_schema = parse(new_input.as_str(), true)?;
+    new_schema.verify()?;
+
+    let old_version = old_schema
+        .version
+        .as_ref()
+        .ok_or(format!("No version declared in {}", old_file))?;
+    let new_version = new_schema
+        .version
+        .as_ref()
+        .ok_or(format!("No version declared in {}", new_file))?;
+
+    let changes = manyleb::diff_schemas(&old_schema, &new_schema);
+    print!("{}", manyleb::format_diff(&changes, DiffFormat::Text));
+
+    match manyleb::check_version(old_version, new_version, &changes)? {
+        Bump::None => println!("\nVersion {} is unchanged.", new_version),
+        bump => println!(
+            "\nVersion {} to {} is {}, and the changes require {}.",
+            old_version,
+            new_version,
+            bump,
+            manyleb::required_bump(&changes)
+        ),
+    }
+
+    Ok(())
+}
+
+fn changelog(files: &[String], docs_url: &str, options: &DocsOptions) -> Result<(), String> {
+    let mut schemas = Vec::new();
+
+    for file in files {
+        let input = std::fs::read_to_string(file).unwrap();
+        let schema = parse(input.as_str(), true).map_err(|e| format!("{}: {}", file, e))?;
+        schema.verify().map_err(|e| format!("{}: {}", file, e))?;
+        schemas.push(schema);
+    }
+
+    print!(
+        "{}",
+        manyleb::generate_changelog_with_options(&schemas, docs_url, options)
+    );
+
+    Ok(())
+}
+
+fn postman(input_file: &String, output_file: &String) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema = parse(input.as_str(), true)?;
+    schema.verify()?;
+
+    let collection = manyleb::generate_postman(&schema);
+    std::fs::write(output_file, format!("{}\n", collection.to_pretty_json(0))).unwrap();
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn dump(input_file: &String) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema = parse(input.as_str(), true)?;
+    schema.verify()?;
+
+    let json = serde_json::to_string_pretty(&schema).map_err(|e| e.to_string())?;
+    println!("{}", json);
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn from_json(input_file: &String) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema: manyleb::Schema = serde_json::from_str(&input).map_err(|e| e.to_string())?;
+    schema.verify()?;
+
+    print!("{}", format_schema(&schema));
+
+    Ok(())
+}
+
+#[cfg(not(feature = "serde"))]
+fn dump(_input_file: &String) -> Result<(), String> {
+    Err("manyleb must be built with the 'serde' feature to dump schemas".to_string())
+}
+
+#[cfg(not(feature = "serde"))]
+fn from_json(_input_file: &String) -> Result<(), String> {
+    Err("manyleb must be built with the 'serde' feature to read JSON schemas".to_string())
+}
+
+fn diagram(input_file: &String, format: DiagramFormat) -> Result<(), String> {
+    let input = std::fs::read_to_string(input_file).unwrap();
+    let schema = parse(input.as_str(), true)?;
+    schema.verify()?;
+
+    print!("{}", manyleb::generate_diagram(&schema, format));
+
+    Ok(())
+}
+
 fn main() {
     let args = std::env::args().collect::<Vec<String>>();
-    if args.len() < 1 {
+    if args.is_empty() {
         eprintln!("Usage: manyleb <command> [args...]");
         std::process::exit(1);
     }
@@ -75,33 +304,305 @@ fn main() {
             }
         }
         "docs" => {
-            if args.len() < 4 {
-                eprintln!("Usage: manyleb docs <input-file> <output-file>");
+            let html = args[2..].iter().any(|a| a == "--html");
+            let options = DocsOptions {
+                group_by_tag: args[2..].iter().any(|a| a == "--group-by-tag"),
+                diagram: args[2..].iter().any(|a| a == "--diagram"),
+                table_of_contents: args[2..].iter().any(|a| a == "--toc"),
+            };
+            let paths: Vec<&String> = args[2..].iter().filter(|a| !a.starts_with("--")).collect();
+            if paths.len() < 2 {
+                eprintln!(
+                    "Usage: manyleb docs [--html] [--group-by-tag] [--diagram] [--toc] <input-file> <output-file-or-dir>"
+                );
                 std::process::exit(1);
             }
 
-            let input_file = &args[2];
-            let output_file = &args[3];
+            let input_file = paths[0];
+            let output = paths[1];
 
-            if let Err(err) = docs(input_file, output_file) {
+            let result = if html {
+                docs_html(input_file, output)
+            } else {
+                docs(input_file, output, &options)
+            };
+            if let Err(err) = result {
                 eprintln!("Error generating docs from file {}: {}", input_file, err);
                 std::process::exit(1);
             }
         }
         "summary" => {
+            let usage = "Usage: manyleb summary <input-file> <output-file> [--format markdown|json|csv|csv-statistics]";
             if args.len() < 4 {
-                eprintln!("Usage: manyleb summary <input-file> <output-file>");
+                eprintln!("{}", usage);
                 std::process::exit(1);
             }
 
             let input_file = &args[2];
             let output_file = &args[3];
+            let format = match args.iter().position(|a| a == "--format") {
+                Some(index) => match args.get(index + 1).map(|f| f.as_str()) {
+                    Some("markdown" | "md") => SummaryFormat::Markdown,
+                    Some("json") => SummaryFormat::Json,
+                    Some("csv") => SummaryFormat::Csv,
+                    Some("csv-statistics") => SummaryFormat::CsvStatistics,
+                    _ => {
+                        eprintln!("{}", usage);
+                        std::process::exit(1);
+                    }
+                },
+                None => SummaryFormat::Markdown,
+            };
 
-            if let Err(err) = summary(input_file, output_file) {
+            if let Err(err) = summary(input_file, output_file, format) {
                 eprintln!("Error generating summary from file {}: {}", input_file, err);
                 std::process::exit(1);
             }
         }
+        "gen" | "generate" => {
+            if args.len() < 5 {
+                eprintln!("Usage: manyleb gen <target> <input-file> <output-file>");
+                eprintln!(
+                    "Targets: go, go-client, kotlin, proto, python, python-dataclasses, rust, rust-server, rust-client, swift, typescript, typescript-client, zod"
+                );
+                std::process::exit(1);
+            }
+
+            let target = &args[2];
+            let input_file = &args[3];
+            let output_file = &args[4];
+
+            if let Err(err) = generate(target, input_file, output_file) {
+                eprintln!(
+                    "Error generating {} from file {}: {}",
+                    target, input_file, err
+                );
+                std::process::exit(1);
+            }
+        }
+        "jsonschema" => {
+            if args.len() < 4 {
+                eprintln!("Usage: manyleb jsonschema <input-file> <output-dir> [--bundle]");
+                std::process::exit(1);
+            }
+
+            let input_file = &args[2];
+            let output_dir = &args[3];
+            let bundle = args[4..].iter().any(|a| a == "--bundle");
+
+            if let Err(err) = jsonschema(input_file, output_dir, bundle) {
+                eprintln!(
+                    "Error generating JSON Schema from file {}: {}",
+                    input_file, err
+                );
+                std::process::exit(1);
+            }
+        }
+        "dump" => {
+            if args.len() < 3 {
+                eprintln!("Usage: manyleb dump <input-file>");
+                std::process::exit(1);
+            }
+
+            let input_file = &args[2];
+
+            if let Err(err) = dump(input_file) {
+                eprintln!("Error dumping file {}: {}", input_file, err);
+                std::process::exit(1);
+            }
+        }
+        "from-json" => {
+            if args.len() < 3 {
+                eprintln!("Usage: manyleb from-json <input-file>");
+                std::process::exit(1);
+            }
+
+            let input_file = &args[2];
+
+            if let Err(err) = from_json(input_file) {
+                eprintln!("Error reading JSON from file {}: {}", input_file, err);
+                std::process::exit(1);
+            }
+        }
+        "diagram" => {
+            let usage =
+                "Usage: manyleb diagram <input-file> [--format mermaid|mermaid-er|dot|plantuml]";
+            if args.len() < 3 {
+                eprintln!("{}", usage);
+                std::process::exit(1);
+            }
+
+            let input_file = &args[2];
+            let format = match args.iter().position(|a| a == "--format") {
+                Some(index) => match args.get(index + 1).map(|f| f.as_str()) {
+                    Some("mermaid") => DiagramFormat::Mermaid,
+                    Some("mermaid-er" | "er") => DiagramFormat::MermaidEr,
+                    Some("dot" | "graphviz") => DiagramFormat::Dot,
+                    Some("plantuml" | "puml") => DiagramFormat::PlantUml,
+                    _ => {
+                        eprintln!("{}", usage);
+                        std::process::exit(1);
+                    }
+                },
+                None => DiagramFormat::Mermaid,
+            };
+
+            if let Err(err) = diagram(input_file, format) {
+                eprintln!("Error generating diagram from file {}: {}", input_file, err);
+                std::process::exit(1);
+            }
+        }
+        "diff" => {
+            let usage = "Usage: manyleb diff <old-file> <new-file> [--format text|json|markdown]";
+            if args.len() < 4 {
+                eprintln!("{}", usage);
+                std::process::exit(1);
+            }
+
+            let old_file = &args[2];
+            let new_file = &args[3];
+            let format = match args.iter().position(|a| a == "--format") {
+                Some(index) => match args.get(index + 1).map(|f| f.as_str()) {
+                    Some("text") => DiffFormat::Text,
+                    Some("json") => DiffFormat::Json,
+                    Some("markdown" | "md") => DiffFormat::Markdown,
+                    _ => {
+                        eprintln!("{}", usage);
+                        std::process::exit(1);
+                    }
+                },
+                None => DiffFormat::Text,
+            };
+
+            match diff(old_file, new_file, format) {
+                Ok(false) => {}
+                Ok(true) => std::process::exit(1),
+                Err(err) => {
+                    eprintln!("Error comparing {} and {}: {}", old_file, new_file, err);
+                    std::process::exit(1);
+                }
+            }
+        }
+        "version-check" => {
+            if args.len() < 4 {
+                eprintln!("Usage: manyleb version-check <old-file> <new-file>");
+                std::process::exit(1);
+            }
+
+            let old_file = &args[2];
+            let new_file = &args[3];
+
+            if let Err(err) = version_check(old_file, new_file) {
+                eprintln!("Version check failed for {}: {}", new_file, err);
+                std::process::exit(1);
+            }
+        }
+        "changelog" => {
+            let usage = "Usage: manyleb changelog <old-file> <new-file> [<newer-file>...] [--docs <docs-url>] [--group-by-tag] [--diagram] [--toc]";
+
+            let mut files = Vec::new();
+            let mut docs_url = String::new();
+            let mut options = DocsOptions::default();
+            let mut rest = args[2..].iter();
+            while let Some(arg) = rest.next() {
+                if arg == "--docs" {
+                    match rest.next() {
+                        Some(url) => docs_url = url.clone(),
+                        None => {
+                            eprintln!("{}", usage);
+                            std::process::exit(1);
+                        }
+                    }
+                } else if arg == "--group-by-tag" {
+                    options.group_by_tag = true;
+                } else if arg == "--diagram" {
+                    options.diagram = true;
+                } else if arg == "--toc" {
+                    options.table_of_contents = true;
+                } else {
+                    files.push(arg.clone());
+                }
+            }
+
+            if files.len() < 2 {
+                eprintln!("{}", usage);
+                std::process::exit(1);
+            }
+
+            if let Err(err) = changelog(&files, &docs_url, &options) {
+                eprintln!("Error generating changelog: {}", err);
+                std::process::exit(1);
+            }
+        }
+        "postman" => {
+            if args.len() < 4 {
+                eprintln!("Usage: manyleb postman <input-file> <output-file>");
+                std::process::exit(1);
+            }
+
+            let input_file = &args[2];
+            let output_file = &args[3];
+
+            if let Err(err) = postman(input_file, output_file) {
+                eprintln!(
+                    "Error generating Postman collection from file {}: {}",
+                    input_file, err
+                );
+                std::process::exit(1);
+            }
+        }
+        "mock" => {
+            if args.len() < 3 {
+                eprintln!("Usage: manyleb mock <input-file> [--port <port>]");
+                std::process::exit(1);
+            }
+
+            let input_file = &args[2];
+            let port = match args.iter().position(|a| a == "--port") {
+                Some(index) => match args.get(index + 1).and_then(|p| p.parse::<u16>().ok()) {
+                    Some(port) => port,
+                    None => {
+                        eprintln!("Usage: manyleb mock <input-file> [--port <port>]");
+                        std::process::exit(1);
+                    }
+                },
+                None => 8080,
+            };
+
+            if let Err(err) = mock(input_file, port) {
+                eprintln!("Error running mock server for file {}: {}", input_file, err);
+                std::process::exit(1);
+            }
+        }
+        "example" => {
+            let usage = "Usage: manyleb example <input-file> <object> [--seed <seed>] [--array-length <length>]";
+            if args.len() < 4 {
+                eprintln!("{}", usage);
+                std::process::exit(1);
+            }
+
+            let input_file = &args[2];
+            let object = &args[3];
+
+            let mut options = ExampleOptions::default();
+            let mut rest = args[4..].iter();
+            while let Some(flag) = rest.next() {
+                let value = rest.next().and_then(|v| v.parse::<u64>().ok());
+                match (flag.as_str(), value) {
+                    ("--seed", Some(seed)) => options.seed = seed,
+                    ("--array-length", Some(length)) => options.array_length = length as usize,
+                    _ => {
+                        eprintln!("{}", usage);
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            if let Err(err) = example(input_file, object, &options) {
+                eprintln!("Error generating example from file {}: {}", input_file, err);
+                std::process::exit(1);
+            }
+        }
         _ => {
             eprintln!("Unknown command: {}", command);
             std::process::exit(1);
@@ -0,0 +1,476 @@
+use std::{
+    io::{BufRead, BufReader, ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    Endpoint, ExampleOptions, JsonValue, Schema, Type, generate_example,
+    generators::path_param_name,
+};
+
+const STATUS_HEADER: &str = "x-mock-status";
+const STATUS_QUERY: &str = "__status";
+/// Request bodies larger than this are rejected with a 413 before being read.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+/// Request lines and header lines longer than this are rejected with a 431.
+const MAX_LINE_SIZE: usize = 8 * 1024;
+/// Requests with more header lines than this are rejected with a 431.
+const MAX_HEADERS: usize = 100;
+/// Clients that stall for longer than this while sending a request or
+/// receiving a response are disconnected.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Request {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+struct Response {
+    status: u16,
+    body: Option<String>,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        let body = JsonValue::Object(vec![(
+            "error".to_string(),
+            JsonValue::String(message.to_string()),
+        )]);
+
+        Response {
+            status,
+            body: Some(body.to_json()),
+        }
+    }
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        414 => "URI Too Long",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+/// Decodes `%XX` escapes. A `+` is left as is, since it only means a space in
+/// query strings.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(byte) => {
+                        result.push(byte);
+                        i += 2;
+                    }
+                    Err(_) => result.push(b'%'),
+                }
+            }
+            b => result.push(b),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&result).to_string()
+}
+
+fn decode_query_component(s: &str) -> String {
+    percent_decode(&s.replace('+', " "))
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (decode_query_component(key), decode_query_component(value)),
+            None => (decode_query_component(pair), String::new()),
+        })
+        .collect()
+}
+
+fn bad_request(message: impl ToString) -> Response {
+    Response::error(400, &message.to_string())
+}
+
+fn read_error(err: std::io::Error) -> Response {
+    match err.kind() {
+        ErrorKind::WouldBlock | ErrorKind::TimedOut => Response::error(408, "Request timed out"),
+        _ => bad_request(err),
+    }
+}
+
+/// Reads a line of at most `MAX_LINE_SIZE` bytes, responding with `status`
+/// if it is longer.
+fn read_line(reader: &mut impl BufRead, status: u16) -> Result<String, Response> {
+    let mut line = String::new();
+    reader
+        .take(MAX_LINE_SIZE as u64 + 1)
+        .read_line(&mut line)
+        .map_err(read_error)?;
+
+    if line.len() > MAX_LINE_SIZE {
+        return Err(Response::error(
+            status,
+            &format!("Line exceeds {} bytes", MAX_LINE_SIZE),
+        ));
+    }
+    Ok(line)
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, Response> {
+    let mut reader = BufReader::new(stream);
+
+    let request_line = read_line(&mut reader, 414)?;
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts
+        .next()
+        .ok_or_else(|| bad_request("Missing request method"))?
+        .to_string();
+    let target = parts
+        .next()
+        .ok_or_else(|| bad_request("Missing request target"))?
+        .to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let line = read_line(&mut reader, 431)?;
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if headers.len() == MAX_HEADERS {
+            return Err(Response::error(
+                431,
+                &format!("Request has more than {} headers", MAX_HEADERS),
+            ));
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    if content_length > MAX_BODY_SIZE {
+        return Err(Response::error(
+            413,
+            &format!("Request body exceeds {} bytes", MAX_BODY_SIZE),
+        ));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(read_error)?;
+
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target, Vec::new()),
+    };
+
+    Ok(Request {
+        method,
+        path,
+        query,
+        headers,
+        body: String::from_utf8_lossy(&body).to_string(),
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> Result<(), String> {
+    let body = response.body.clone().unwrap_or_default();
+
+    let mut output = format!(
+        "HTTP/1.1 {} {}\r\n",
+        response.status,
+        reason_phrase(response.status)
+    );
+    output.push_str("Access-Control-Allow-Origin: *\r\n");
+    output.push_str("Access-Control-Allow-Methods: *\r\n");
+    output.push_str("Access-Control-Allow-Headers: *\r\n");
+    if response.body.is_some() {
+        output.push_str("Content-Type: application/json\r\n");
+    }
+    output.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    output.push_str("Connection: close\r\n\r\n");
+    output.push_str(&body);
+
+    stream
+        .write_all(output.as_bytes())
+        .map_err(|e| e.to_string())
+}
+
+fn match_path(pattern: &str, path: &str) -> Option<Vec<(String, String)>> {
+    let pattern_segments: Vec<&str> = pattern.trim_matches('/').split('/').collect();
+    let path_segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    if pattern_segments.len() != path_segments.len() {
+        return None;
+    }
+
+    let mut params = Vec::new();
+
+    for (pattern_segment, path_segment) in pattern_segments.iter().zip(path_segments.iter()) {
+        match path_param_name(pattern_segment) {
+            Some(name) => params.push((name.to_string(), percent_decode(path_segment))),
+            None if pattern_segment == path_segment => {}
+            None => return None,
+        }
+    }
+
+    Some(params)
+}
+
+fn validate_primitive(value: &str, t: &Type) -> bool {
+    match t {
+        Type::Integer => value.parse::<i64>().is_ok(),
+        Type::Float => value.parse::<f64>().is_ok(),
+        Type::Boolean => value == "true" || value == "false",
+        Type::Optional(inner) => validate_primitive(value, inner),
+        _ => true,
+    }
+}
+
+fn select_response<'a>(
+    endpoint: &'a Endpoint,
+    request: &Request,
+) -> Result<&'a (u16, Option<Type>), String> {
+    let requested = request
+        .headers
+        .iter()
+        .find(|(name, _)| name == STATUS_HEADER)
+        .map(|(_, value)| value.clone())
+        .or_else(|| {
+            request
+                .query
+                .iter()
+                .find(|(name, _)| name == STATUS_QUERY)
+                .map(|(_, value)| value.clone())
+        });
+
+    if let Some(requested) = requested {
+        let status = requested
+            .parse::<u16>()
+            .map_err(|_| format!("Invalid requested status code '{}'", requested))?;
+
+        return endpoint
+            .responses
+            .iter()
+            .find(|(code, _)| *code == status)
+            .ok_or_else(|| {
+                format!(
+                    "Status code {} is not declared for {} {}",
+                    status,
+                    endpoint.method.to_uppercase(),
+                    endpoint.path
+                )
+            });
+    }
+
+    endpoint
+        .responses
+        .iter()
+        .find(|(code, _)| (200..300).contains(code))
+        .or_else(|| endpoint.responses.first())
+        .ok_or_else(|| {
+            format!(
+                "No responses declared for {} {}",
+                endpoint.method.to_uppercase(),
+                endpoint.path
+            )
+        })
+}
+
+fn validate_request(
+    schema: &Schema,
+    endpoint: &Endpoint,
+    request: &Request,
+    params: &[(String, String)],
+) -> Result<(), String> {
+    for param in &endpoint.params {
+        if let Some((_, value)) = params.iter().find(|(name, _)| name == &param.name)
+            && !validate_primitive(value, &param.field_type)
+        {
+            return Err(format!(
+                "Invalid value '{}' for path parameter '{}': expected {}",
+                value, param.name, param.field_type
+            ));
+        }
+    }
+
+    for query in &endpoint.query {
+        match request.query.iter().find(|(name, _)| name == &query.name) {
+            Some((_, value)) => {
+                if !validate_primitive(value, &query.field_type) {
+                    return Err(format!(
+                        "Invalid value '{}' for query parameter '{}': expected {}",
+                        value, query.name, query.field_type
+                    ));
+                }
+            }
+            None if matches!(query.field_type, Type::Optional(_)) => {}
+            None => {
+                return Err(format!("Missing required query parameter '{}'", query.name));
+            }
+        }
+    }
+
+    if let Some(body_type) = &endpoint.request_body {
+        if request.body.trim().is_empty() {
+            if !matches!(body_type, Type::Optional(_)) {
+                return Err("Missing request body".to_string());
+            }
+        } else {
+            let body = JsonValue::parse(&request.body)?;
+            schema.validate_value(&body, body_type)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_request(schema: &Schema, request: &Request) -> Response {
+    let options = ExampleOptions {
+        include_optional: true,
+        ..ExampleOptions::default()
+    };
+
+    if request.method.eq_ignore_ascii_case("options") {
+        return Response {
+            status: 204,
+            body: None,
+        };
+    }
+
+    let mut path_matched = false;
+
+    for endpoint in &schema.endpoints {
+        let Some(params) = match_path(&endpoint.path, &request.path) else {
+            continue;
+        };
+        path_matched = true;
+
+        if !endpoint.method.eq_ignore_ascii_case(&request.method) {
+            continue;
+        }
+
+        if let Err(err) = validate_request(schema, endpoint, request, &params) {
+            return Response::error(400, &err);
+        }
+
+        return match select_response(endpoint, request) {
+            Ok((status, Some(response_type))) => {
+                let example = endpoint
+                    .response_examples
+                    .iter()
+                    .find(|(code, _)| code == status)
+                    .map(|(_, example)| example.clone())
+                    .unwrap_or_else(|| generate_example(schema, response_type, &options));
+
+                Response {
+                    status: *status,
+                    body: Some(example.to_json()),
+                }
+            }
+            Ok((status, None)) => Response {
+                status: *status,
+                body: None,
+            },
+            Err(err) => Response::error(400, &err),
+        };
+    }
+
+    if path_matched {
+        Response::error(405, "Method not allowed")
+    } else {
+        Response::error(404, "No matching endpoint")
+    }
+}
+
+pub fn serve_mock(schema: &Schema, port: u16) -> Result<(), String> {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .map_err(|e| format!("Failed to bind to port {}: {}", port, e))?;
+
+    println!("Mock server listening on http://127.0.0.1:{}", port);
+
+    let schema = Arc::new(schema.clone());
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("Failed to accept connection: {}", err);
+                continue;
+            }
+        };
+
+        let schema = Arc::clone(&schema);
+        thread::spawn(move || handle_connection(&schema, stream));
+    }
+
+    Ok(())
+}
+
+fn handle_connection(schema: &Schema, mut stream: TcpStream) {
+    if let Err(err) = stream
+        .set_read_timeout(Some(TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)))
+    {
+        eprintln!("Failed to set connection timeouts: {}", err);
+        return;
+    }
+
+    let response = match read_request(&mut stream) {
+        Ok(request) => {
+            let response = handle_request(schema, &request);
+            println!(
+                "{} {} -> {}",
+                request.method.to_uppercase(),
+                request.path,
+                response.status
+            );
+            response
+        }
+        Err(response) => response,
+    };
+
+    if let Err(err) = write_response(&mut stream, &response) {
+        eprintln!("Failed to write response: {}", err);
+    }
+}
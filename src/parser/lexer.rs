@@ -1,12 +1,41 @@
 use crate::parser::token::{Token, TokenContext};
 
 struct Lexer {
-    input: String,
+    input: Vec<char>,
     line: usize,
     column: usize,
     index: usize,
 }
 
+fn read_hex_escape(chars: &mut impl Iterator<Item = char>) -> Result<u32, String> {
+    let hex: String = chars.take(4).collect();
+    if hex.len() != 4 {
+        return Err(format!("Invalid escape sequence: \\u{}", hex));
+    }
+
+    u32::from_str_radix(&hex, 16).map_err(|_| format!("Invalid escape sequence: \\u{}", hex))
+}
+
+/// Decodes a `\uXXXX` escape, combining UTF-16 surrogate pairs.
+pub(crate) fn read_unicode_escape(chars: &mut impl Iterator<Item = char>) -> Result<char, String> {
+    let code = read_hex_escape(chars)?;
+
+    let code = if (0xd800..0xdc00).contains(&code) {
+        if chars.next() != Some('\\') || chars.next() != Some('u') {
+            return Err("Unpaired surrogate in unicode escape".to_string());
+        }
+        let low = read_hex_escape(chars)?;
+        if !(0xdc00..0xe000).contains(&low) {
+            return Err("Unpaired surrogate in unicode escape".to_string());
+        }
+        0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00)
+    } else {
+        code
+    };
+
+    char::from_u32(code).ok_or_else(|| format!("Invalid unicode escape: {:04x}", code))
+}
+
 fn strip_escapes(s: &str) -> Result<String, String> {
     let mut result = String::new();
     let mut chars = s.chars().peekable();
@@ -19,6 +48,7 @@ fn strip_escapes(s: &str) -> Result<String, String> {
                 Some('r') => result.push('\r'),
                 Some('"') => result.push('"'),
                 Some('\\') => result.push('\\'),
+                Some('u') => result.push(read_unicode_escape(&mut chars)?),
                 Some(other) => return Err(format!("Invalid escape sequence: \\{}", other)),
                 None => return Err("Invalid escape sequence at end of string".to_string()),
             }
@@ -30,18 +60,58 @@ fn strip_escapes(s: &str) -> Result<String, String> {
     Ok(result)
 }
 
+fn is_number_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '+' | '-' | '_')
+}
+
 impl Lexer {
-    pub fn new(input: String) -> Self {
+    pub fn new(input: &str) -> Self {
         Lexer {
-            input,
+            input: input.chars().collect(),
             line: 1,
             column: 1,
             index: 0,
         }
     }
 
+    fn peek(&self) -> Option<char> {
+        self.input.get(self.index).copied()
+    }
+
+    /// Skips a run of digits, returning how many were skipped.
+    fn skip_digits(&mut self) -> usize {
+        let start_index = self.index;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.index += 1;
+        }
+        self.index - start_index
+    }
+
+    fn error_message(&self, message: &str) -> String {
+        format!(
+            "Error at line {}, column {}: {}",
+            self.line, self.column, message
+        )
+    }
+
+    /// Reports the malformed number starting at `start_index`, including the
+    /// rest of the word it is part of.
+    fn invalid_number(&self, start_index: usize) -> String {
+        let number: String = self
+            .input
+            .iter()
+            .copied()
+            .skip(start_index)
+            .enumerate()
+            .take_while(|(i, c)| *i == 0 || is_number_char(*c))
+            .map(|(_, c)| c)
+            .collect();
+
+        self.error_message(&format!("Invalid number '{}'", number))
+    }
+
     fn get_token(&mut self) -> Result<Option<TokenContext>, String> {
-        let char = match self.input.chars().nth(self.index) {
+        let char = match self.input.get(self.index).copied() {
             Some(c) => c,
             None => return Ok(None),
         };
@@ -51,12 +121,13 @@ impl Lexer {
                 let start_index = self.index + 1;
                 self.index += 1;
 
-                while let Some(c) = self.input.chars().nth(self.index) {
+                while let Some(c) = self.input.get(self.index).copied() {
                     if c == '"' {
-                        let string_value = &self.input[start_index..self.index];
+                        let string_value: String =
+                            self.input[start_index..self.index].iter().collect();
                         self.index += 1;
                         return Ok(Some(TokenContext {
-                            token: Token::String(strip_escapes(string_value)?),
+                            token: Token::String(strip_escapes(&string_value)?),
                             line: self.line,
                             column: self.column,
                         }));
@@ -67,24 +138,58 @@ impl Lexer {
                     }
                 }
 
-                Err("Unterminated string literal".to_string())
+                Err(self.error_message("Unterminated string literal"))
             }
-            '1'..='9' => {
+            '-' | '0'..='9' => {
                 let start_index = self.index;
+                let mut is_float = false;
 
-                while let Some(c) = self.input.chars().nth(self.index) {
-                    if c.is_digit(10) {
+                if char == '-' {
+                    self.index += 1;
+                }
+                if self.skip_digits() == 0 {
+                    return Err(self.invalid_number(start_index));
+                }
+
+                if self.peek() == Some('.') {
+                    is_float = true;
+                    self.index += 1;
+                    if self.skip_digits() == 0 {
+                        return Err(self.invalid_number(start_index));
+                    }
+                }
+
+                if matches!(self.peek(), Some('e' | 'E')) {
+                    is_float = true;
+                    self.index += 1;
+                    if matches!(self.peek(), Some('+' | '-')) {
                         self.index += 1;
-                    } else {
-                        break;
                     }
+                    if self.skip_digits() == 0 {
+                        return Err(self.invalid_number(start_index));
+                    }
+                }
+
+                if self.peek().is_some_and(is_number_char) {
+                    return Err(self.invalid_number(start_index));
                 }
 
-                let int_value = &self.input[start_index..self.index];
-                let int_parsed = int_value.parse::<i64>().map_err(|e| e.to_string())?;
+                let number_value: String = self.input[start_index..self.index].iter().collect();
+
+                let token = if is_float {
+                    Token::Float(
+                        number_value
+                            .parse::<f64>()
+                            .map_err(|_| self.invalid_number(start_index))?,
+                    )
+                } else {
+                    Token::Integer(number_value.parse::<i64>().map_err(|_| {
+                        self.error_message(&format!("Integer '{}' is out of range", number_value))
+                    })?)
+                };
 
                 Ok(Some(TokenContext {
-                    token: Token::Integer(int_parsed),
+                    token,
                     line: self.line,
                     column: self.column,
                 }))
@@ -92,7 +197,7 @@ impl Lexer {
             'a'..='z' | 'A'..='Z' | '_' => {
                 let start_index = self.index;
 
-                while let Some(c) = self.input.chars().nth(self.index) {
+                while let Some(c) = self.input.get(self.index).copied() {
                     if c.is_alphanumeric() || c == '_' {
                         self.index += 1;
                     } else {
@@ -100,9 +205,9 @@ impl Lexer {
                     }
                 }
 
-                let ident_value = &self.input[start_index..self.index];
+                let ident_value: String = self.input[start_index..self.index].iter().collect();
 
-                let token = match ident_value {
+                let token = match ident_value.as_str() {
                     "version" => Token::KWVersion,
                     "title" => Token::KWTitle,
                     "description" => Token::KWDescription,
@@ -114,7 +219,7 @@ impl Lexer {
                     "tag" => Token::KWTag,
                     "param" => Token::KWParam,
                     "query" => Token::KWQuery,
-                    _ => Token::Identifier(ident_value.to_string()),
+                    _ => Token::Identifier(ident_value),
                 };
 
                 Ok(Some(TokenContext {
@@ -187,6 +292,14 @@ impl Lexer {
                     column: self.column,
                 }))
             }
+            ':' => {
+                self.index += 1;
+                Ok(Some(TokenContext {
+                    token: Token::Colon,
+                    line: self.line,
+                    column: self.column,
+                }))
+            }
             '@' => {
                 self.index += 1;
                 Ok(Some(TokenContext {
@@ -205,7 +318,7 @@ impl Lexer {
                 self.index += 1;
                 Ok(None)
             }
-            _ => Err(format!("Unexpected character: {}", char)),
+            _ => Err(self.error_message(&format!("Unexpected character: {}", char))),
         }
     }
 
@@ -213,7 +326,9 @@ impl Lexer {
         let mut tokens = Vec::new();
 
         while self.index < self.input.len() {
+            let start_index = self.index;
             if let Some(token) = self.get_token()? {
+                self.column += self.index - start_index;
                 tokens.push(token);
             }
         }
@@ -223,5 +338,5 @@ impl Lexer {
 }
 
 pub fn tokenise(input: &str) -> Result<Vec<TokenContext>, String> {
-    Lexer::new(input.into()).tokenise()
+    Lexer::new(input).tokenise()
 }
@@ -1,5 +1,7 @@
 mod lexer;
+#[allow(clippy::module_inception)]
 mod parser;
 mod token;
 
+pub(crate) use lexer::read_unicode_escape;
 pub use parser::parse;
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use crate::{
-    Endpoint, Field, Object, Schema, Type,
+    Endpoint, Field, JsonValue, Object, Schema, Type,
     parser::{
         lexer::tokenise,
         token::{Token, TokenContext},
@@ -30,11 +30,11 @@ impl Parser {
     }
 
     fn expect_keyword(&mut self, expected: Token) -> Result<(), String> {
-        if let Some(token_context) = self.tokens.get(self.position) {
-            if token_context.token == expected {
-                self.position += 1;
-                return Ok(());
-            }
+        if let Some(token_context) = self.tokens.get(self.position)
+            && token_context.token == expected
+        {
+            self.position += 1;
+            return Ok(());
         }
 
         match expected {
@@ -51,33 +51,33 @@ impl Parser {
     }
 
     fn expect_identifier(&mut self) -> Result<String, String> {
-        if let Some(token_context) = self.tokens.get(self.position) {
-            if let Token::Identifier(name) = &token_context.token {
-                self.position += 1;
-                return Ok(name.clone());
-            }
+        if let Some(token_context) = self.tokens.get(self.position)
+            && let Token::Identifier(name) = &token_context.token
+        {
+            self.position += 1;
+            return Ok(name.clone());
         }
 
         Err(self.error_message("Expected identifier."))
     }
 
     fn expect_string(&mut self) -> Result<String, String> {
-        if let Some(token_context) = self.tokens.get(self.position) {
-            if let Token::String(value) = &token_context.token {
-                self.position += 1;
-                return Ok(value.clone());
-            }
+        if let Some(token_context) = self.tokens.get(self.position)
+            && let Token::String(value) = &token_context.token
+        {
+            self.position += 1;
+            return Ok(value.clone());
         }
 
         Err(self.error_message("Expected string."))
     }
 
     fn expect_block_start(&mut self) -> Result<(), String> {
-        if let Some(token_context) = self.tokens.get(self.position) {
-            if let Token::BlockStart = &token_context.token {
-                self.position += 1;
-                return Ok(());
-            }
+        if let Some(token_context) = self.tokens.get(self.position)
+            && let Token::BlockStart = &token_context.token
+        {
+            self.position += 1;
+            return Ok(());
         }
 
         Err(self.error_message("Expected block start '{'."))
@@ -122,21 +122,129 @@ impl Parser {
         Err(self.error_message("Expected type."))
     }
 
+    fn expect_json(&mut self) -> Result<JsonValue, String> {
+        self.position += 1;
+
+        let Some(token_context) = self.tokens.get(self.position - 1) else {
+            return Err(self.error_message("Expected JSON value."));
+        };
+
+        match &token_context.token {
+            Token::String(value) => Ok(JsonValue::String(value.clone())),
+            Token::Integer(value) => Ok(JsonValue::Integer(*value)),
+            Token::Float(value) => Ok(JsonValue::Float(*value)),
+            Token::Identifier(name) => match name.as_str() {
+                "true" => Ok(JsonValue::Boolean(true)),
+                "false" => Ok(JsonValue::Boolean(false)),
+                "null" => Ok(JsonValue::Null),
+                _ => Err(token_context.error_message("Expected JSON value.")),
+            },
+            Token::ArayStart => {
+                let mut items = Vec::new();
+
+                if let Some(TokenContext {
+                    token: Token::ArayEnd,
+                    ..
+                }) = self.tokens.get(self.position)
+                {
+                    self.position += 1;
+                    return Ok(JsonValue::Array(items));
+                }
+
+                loop {
+                    items.push(self.expect_json()?);
+
+                    match self.tokens.get(self.position).map(|t| &t.token) {
+                        Some(Token::Comma) => self.position += 1,
+                        Some(Token::ArayEnd) => {
+                            self.position += 1;
+                            return Ok(JsonValue::Array(items));
+                        }
+                        _ => return Err(self.error_message("Expected ',' or ']' in JSON array.")),
+                    }
+                }
+            }
+            Token::BlockStart => {
+                let mut entries = Vec::new();
+
+                if let Some(TokenContext {
+                    token: Token::BlockEnd,
+                    ..
+                }) = self.tokens.get(self.position)
+                {
+                    self.position += 1;
+                    return Ok(JsonValue::Object(entries));
+                }
+
+                loop {
+                    let key = self.expect_string()?;
+
+                    match self.tokens.get(self.position).map(|t| &t.token) {
+                        Some(Token::Colon) => self.position += 1,
+                        _ => return Err(self.error_message("Expected ':' in JSON object.")),
+                    }
+
+                    entries.push((key, self.expect_json()?));
+
+                    match self.tokens.get(self.position).map(|t| &t.token) {
+                        Some(Token::Comma) => self.position += 1,
+                        Some(Token::BlockEnd) => {
+                            self.position += 1;
+                            return Ok(JsonValue::Object(entries));
+                        }
+                        _ => return Err(self.error_message("Expected ',' or '}' in JSON object.")),
+                    }
+                }
+            }
+            _ => Err(token_context.error_message("Expected JSON value.")),
+        }
+    }
+
+    /// `example` is only a keyword where an example is allowed, so it can
+    /// still be used as a name elsewhere.
+    fn at_example(&self) -> bool {
+        matches!(
+            self.tokens.get(self.position),
+            Some(TokenContext {
+                token: Token::Identifier(name),
+                ..
+            }) if name == "example"
+        )
+    }
+
+    fn parse_example(&mut self) -> Result<Option<JsonValue>, String> {
+        if self.at_example() {
+            self.position += 1;
+            return Ok(Some(self.expect_json()?));
+        }
+
+        Ok(None)
+    }
+
     fn expect_property(&mut self) -> Result<Field, String> {
         let name = self.expect_identifier()?;
         let field_type = self.expect_type()?;
         let description = self.expect_string()?;
 
+        // An example on a later line belongs to the enclosing object instead.
+        let line = self.tokens[self.position - 1].line;
+        let example = match self.tokens.get(self.position) {
+            Some(token) if token.line == line => self.parse_example()?,
+            _ => None,
+        };
+
         Ok(Field {
             name,
             description,
             field_type,
+            example,
         })
     }
 
     fn parse_object(&mut self) -> Result<Object, String> {
         let id = self.expect_identifier()?;
         let mut fields = Vec::new();
+        let mut example = None;
 
         self.expect_block_start()?;
 
@@ -147,6 +255,14 @@ impl Parser {
                     let field = self.expect_property()?;
                     fields.push(field);
                 }
+                Token::Identifier(name) if name == "example" => {
+                    if example.is_some() {
+                        return Err(self.error_message("Multiple examples declared for object."));
+                    }
+
+                    self.position += 1;
+                    example = Some(self.expect_json()?);
+                }
                 Token::BlockEnd => {
                     self.position += 1;
                     break;
@@ -159,7 +275,11 @@ impl Parser {
             }
         }
 
-        Ok(Object { id, fields })
+        Ok(Object {
+            id,
+            fields,
+            example,
+        })
     }
 
     fn parse_route(&mut self) -> Result<Endpoint, String> {
@@ -173,7 +293,9 @@ impl Parser {
         let mut params = Vec::new();
         let mut query = Vec::new();
         let mut body = None;
+        let mut body_example = None;
         let mut responses = Vec::new();
+        let mut response_examples = Vec::new();
 
         while let Some(token) = self.tokens.get(self.position) {
             match &token.token {
@@ -190,6 +312,7 @@ impl Parser {
                     self.position += 1;
                     let body_type = self.expect_type()?;
                     body = Some(body_type);
+                    body_example = self.parse_example()?;
                 }
                 Token::KWParam => {
                     self.position += 1;
@@ -211,6 +334,9 @@ impl Parser {
 
                             if let Ok(t) = self.expect_type() {
                                 responses.push((status_code as u16, Some(t)));
+                                if let Some(example) = self.parse_example()? {
+                                    response_examples.push((status_code as u16, example));
+                                }
                                 continue;
                             }
 
@@ -246,22 +372,28 @@ impl Parser {
             params,
             query,
             request_body: body,
+            request_body_example: body_example,
             responses,
+            response_examples,
         })
     }
 
     fn parse_resolve(&mut self) -> Result<String, String> {
-        if let Some(token_context) = self.tokens.get(self.position) {
-            if let Token::String(s) = &token_context.token {
-                self.position += 1;
-                return Ok(s.clone());
-            }
+        if let Some(token_context) = self.tokens.get(self.position)
+            && let Token::String(s) = &token_context.token
+        {
+            self.position += 1;
+            return Ok(s.clone());
         }
 
         Err(self.error_message("Expected string after '@' token."))
     }
 
-    pub fn parse(&mut self, resolve: bool, visited: &mut HashSet<String>) -> Result<Schema, String> {
+    pub fn parse(
+        &mut self,
+        resolve: bool,
+        visited: &mut HashSet<String>,
+    ) -> Result<Schema, String> {
         let mut schema = Schema::new();
 
         while self.position < self.tokens.len() {
@@ -272,11 +404,14 @@ impl Parser {
                     self.position += 1;
                     let resolve_path = self.parse_resolve()?;
                     if !resolve {
-                        continue
+                        continue;
                     }
 
                     if visited.contains(&resolve_path) {
-                        return Err(format!("Circular reference detected for '{}'", resolve_path));
+                        return Err(format!(
+                            "Circular reference detected for '{}'",
+                            resolve_path
+                        ));
                     }
 
                     visited.insert(resolve_path.clone());
@@ -15,6 +15,7 @@ pub enum Token {
     Identifier(String),
     String(String),
     Integer(i64),
+    Float(f64),
 
     BlockStart,
     BlockEnd,
@@ -23,6 +24,7 @@ pub enum Token {
     MapStart,
     MapEnd,
     Comma,
+    Colon,
     QuestionMark,
 
     Resolve,
@@ -37,6 +39,9 @@ pub struct TokenContext {
 
 impl TokenContext {
     pub fn error_message(&self, message: &str) -> String {
-        format!("Error at line {}, column {}: {}", self.line, self.column, message)
+        format!(
+            "Error at line {}, column {}: {}",
+            self.line, self.column, message
+        )
     }
 }
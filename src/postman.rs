@@ -0,0 +1,232 @@
+use crate::{
+    Endpoint, ExampleOptions, Field, JsonValue, Schema, Type, generate_example,
+    generators::path_param_name,
+};
+
+const COLLECTION_SCHEMA: &str =
+    "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+fn string(s: &str) -> JsonValue {
+    JsonValue::String(s.to_string())
+}
+
+fn object(entries: Vec<(&str, JsonValue)>) -> JsonValue {
+    JsonValue::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+/// Percent-encodes everything but unreserved characters, so that values
+/// containing `&`, `=`, `#` or spaces survive in a raw URL.
+fn encode_query_component(s: &str) -> String {
+    let mut encoded = String::new();
+
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    encoded
+}
+
+/// Formats a declared or generated example as it would appear in a URL.
+fn example_value(schema: &Schema, field: &Field) -> String {
+    let field_type = match &field.field_type {
+        Type::Optional(inner_type) => inner_type,
+        field_type => field_type,
+    };
+
+    let example = match &field.example {
+        Some(example) => example.clone(),
+        None => generate_example(schema, field_type, &ExampleOptions::default()),
+    };
+
+    match example {
+        JsonValue::String(s) => s,
+        example => example.to_json(),
+    }
+}
+
+fn format_request(schema: &Schema, endpoint: &Endpoint) -> JsonValue {
+    let path: Vec<String> = endpoint
+        .path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match path_param_name(segment) {
+            Some(name) => format!(":{}", name),
+            None => segment.to_string(),
+        })
+        .collect();
+
+    let mut raw = format!("{{{{baseUrl}}}}/{}", path.join("/"));
+    let enabled: Vec<String> = endpoint
+        .query
+        .iter()
+        .filter(|q| !matches!(q.field_type, Type::Optional(_)))
+        .map(|q| {
+            format!(
+                "{}={}",
+                encode_query_component(&q.name),
+                encode_query_component(&example_value(schema, q))
+            )
+        })
+        .collect();
+    if !enabled.is_empty() {
+        raw.push_str(&format!("?{}", enabled.join("&")));
+    }
+
+    let mut url = vec![
+        ("raw", string(&raw)),
+        ("host", JsonValue::Array(vec![string("{{baseUrl}}")])),
+        (
+            "path",
+            JsonValue::Array(path.iter().map(|s| string(s)).collect()),
+        ),
+    ];
+
+    if !endpoint.query.is_empty() {
+        let query = endpoint
+            .query
+            .iter()
+            .map(|q| {
+                object(vec![
+                    ("key", string(&q.name)),
+                    ("value", string(&example_value(schema, q))),
+                    ("description", string(&q.description)),
+                    (
+                        "disabled",
+                        JsonValue::Boolean(matches!(q.field_type, Type::Optional(_))),
+                    ),
+                ])
+            })
+            .collect();
+        url.push(("query", JsonValue::Array(query)));
+    }
+
+    if !endpoint.params.is_empty() {
+        let variables = endpoint
+            .params
+            .iter()
+            .map(|p| {
+                object(vec![
+                    ("key", string(&p.name)),
+                    ("value", string(&example_value(schema, p))),
+                    ("description", string(&p.description)),
+                ])
+            })
+            .collect();
+        url.push(("variable", JsonValue::Array(variables)));
+    }
+
+    let mut request = vec![("method", string(&endpoint.method.to_uppercase()))];
+
+    if let Some(body_type) = &endpoint.request_body {
+        let example = match &endpoint.request_body_example {
+            Some(example) => example.clone(),
+            None => generate_example(schema, body_type, &ExampleOptions::default()),
+        };
+
+        request.push((
+            "header",
+            JsonValue::Array(vec![object(vec![
+                ("key", string("Content-Type")),
+                ("value", string("application/json")),
+            ])]),
+        ));
+        request.push((
+            "body",
+            object(vec![
+                ("mode", string("raw")),
+                ("raw", string(&example.to_pretty_json(0))),
+                (
+                    "options",
+                    object(vec![("raw", object(vec![("language", string("json"))]))]),
+                ),
+            ]),
+        ));
+    } else {
+        request.push(("header", JsonValue::Array(Vec::new())));
+    }
+
+    request.push(("url", object(url)));
+    if let Some(description) = &endpoint.description {
+        request.push(("description", string(description)));
+    }
+
+    object(vec![
+        (
+            "name",
+            string(&format!(
+                "{} {}",
+                endpoint.method.to_uppercase(),
+                endpoint.path
+            )),
+        ),
+        ("request", object(request)),
+        ("response", JsonValue::Array(Vec::new())),
+    ])
+}
+
+/// Generates a Postman v2.1 collection, which Insomnia can also import. Each
+/// endpoint is placed in a folder per tag, and untagged endpoints at the root.
+pub fn generate_postman(schema: &Schema) -> JsonValue {
+    let mut folders: Vec<(String, Vec<JsonValue>)> = Vec::new();
+    let mut items = Vec::new();
+
+    for endpoint in &schema.endpoints {
+        let request = format_request(schema, endpoint);
+
+        if endpoint.tags.is_empty() {
+            items.push(request);
+            continue;
+        }
+
+        for tag in &endpoint.tags {
+            match folders.iter_mut().find(|(name, _)| name == tag) {
+                Some((_, requests)) => requests.push(request.clone()),
+                None => folders.push((tag.clone(), vec![request.clone()])),
+            }
+        }
+    }
+
+    let mut collection_items: Vec<JsonValue> = folders
+        .into_iter()
+        .map(|(name, requests)| {
+            object(vec![
+                ("name", string(&name)),
+                ("item", JsonValue::Array(requests)),
+            ])
+        })
+        .collect();
+    collection_items.extend(items);
+
+    let mut info = vec![(
+        "name",
+        string(schema.title.as_deref().unwrap_or("Manyleb API")),
+    )];
+    if let Some(description) = &schema.description {
+        info.push(("description", string(description)));
+    }
+    if let Some(version) = &schema.version {
+        info.push(("version", string(version)));
+    }
+    info.push(("schema", string(COLLECTION_SCHEMA)));
+
+    object(vec![
+        ("info", object(info)),
+        ("item", JsonValue::Array(collection_items)),
+        (
+            "variable",
+            JsonValue::Array(vec![object(vec![
+                ("key", string("baseUrl")),
+                ("value", string("http://localhost:8080")),
+            ])]),
+        ),
+    ])
+}
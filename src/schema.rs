@@ -1,4 +1,14 @@
-#[derive(Debug, Clone)]
+use crate::JsonValue;
+
+/// With the `serde` feature, types are represented as `{"kind": "string"}`,
+/// with the inner types of references, arrays and optionals under `of`, and
+/// the key and value types of mappings as an `of` array of two.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "kind", content = "of", rename_all = "snake_case")
+)]
 pub enum Type {
     Any,
     Null,
@@ -13,19 +23,40 @@ pub enum Type {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Field {
     pub name: String,
     pub description: String,
     pub field_type: Type,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::json::deserialize_example"
+        )
+    )]
+    pub example: Option<JsonValue>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Object {
     pub id: String,
     pub fields: Vec<Field>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::json::deserialize_example"
+        )
+    )]
+    pub example: Option<JsonValue>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Endpoint {
     pub id: String,
     pub method: String,
@@ -35,10 +66,21 @@ pub struct Endpoint {
     pub params: Vec<Field>,
     pub query: Vec<Field>,
     pub request_body: Option<Type>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::json::deserialize_example"
+        )
+    )]
+    pub request_body_example: Option<JsonValue>,
     pub responses: Vec<(u16, Option<Type>)>,
+    pub response_examples: Vec<(u16, JsonValue)>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Schema {
     pub version: Option<String>,
     pub title: Option<String>,
@@ -47,6 +89,12 @@ pub struct Schema {
     pub endpoints: Vec<Endpoint>,
 }
 
+impl Default for Schema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Schema {
     pub fn new() -> Self {
         Schema {
@@ -58,46 +106,124 @@ impl Schema {
         }
     }
 
+    pub fn get_object(&self, id: &str) -> Option<&Object> {
+        self.objects.iter().find(|o| o.id == id)
+    }
+
+    pub fn validate_value(&self, value: &JsonValue, t: &Type) -> Result<(), String> {
+        self.validate_value_at(value, t, "$")
+    }
+
+    fn validate_value_at(&self, value: &JsonValue, t: &Type, path: &str) -> Result<(), String> {
+        let valid = match (t, value) {
+            (Type::Any, _) => true,
+            (Type::Optional(_), JsonValue::Null) => true,
+            (Type::Optional(inner), _) => return self.validate_value_at(value, inner, path),
+            (Type::Null, JsonValue::Null) => true,
+            (Type::String, JsonValue::String(_)) => true,
+            (Type::Integer, JsonValue::Integer(_)) => true,
+            (Type::Float, JsonValue::Integer(_) | JsonValue::Float(_)) => true,
+            (Type::Boolean, JsonValue::Boolean(_)) => true,
+            (Type::Array(item_type), JsonValue::Array(items)) => {
+                for (i, item) in items.iter().enumerate() {
+                    self.validate_value_at(item, item_type, &format!("{}[{}]", path, i))?;
+                }
+                true
+            }
+            (Type::Mapping(key_type, value_type), JsonValue::Object(entries)) => {
+                for (key, item) in entries {
+                    let key_valid = match key_type.as_ref() {
+                        Type::Integer => key.parse::<i64>().is_ok(),
+                        Type::Float => key.parse::<f64>().is_ok(),
+                        Type::Boolean => key == "true" || key == "false",
+                        _ => true,
+                    };
+                    if !key_valid {
+                        return Err(format!(
+                            "Invalid key '{}' at {}: expected {}",
+                            key, path, key_type
+                        ));
+                    }
+                    self.validate_value_at(item, value_type, &format!("{}.{}", path, key))?;
+                }
+                true
+            }
+            (Type::Reference(ref_name), JsonValue::Object(entries)) => {
+                let object = self
+                    .get_object(ref_name)
+                    .ok_or_else(|| format!("Undefined object reference '{}'", ref_name))?;
+
+                for field in &object.fields {
+                    match entries.iter().find(|(k, _)| k == &field.name) {
+                        Some((_, item)) => self.validate_value_at(
+                            item,
+                            &field.field_type,
+                            &format!("{}.{}", path, field.name),
+                        )?,
+                        None if matches!(field.field_type, Type::Optional(_)) => {}
+                        None => {
+                            return Err(format!(
+                                "Missing required property '{}' at {}",
+                                field.name, path
+                            ));
+                        }
+                    }
+                }
+                true
+            }
+            _ => false,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(format!(
+                "Invalid value at {}: expected {}, found {}",
+                path,
+                t,
+                value.to_json()
+            ))
+        }
+    }
+
     pub fn verify(&self) -> Result<(), String> {
         let objects: std::collections::HashSet<_> = self.objects.iter().map(|o| &o.id).collect();
 
         for object in &self.objects {
             for field in &object.fields {
                 match &field.field_type {
-                    Type::Reference(ref_name) => {
-                        if !objects.contains(ref_name) {
+                    Type::Reference(ref_name) if !objects.contains(ref_name) => {
+                        return Err(format!(
+                            "Undefined object reference '{}' in field '{}' of object '{}'",
+                            ref_name, field.name, object.id
+                        ));
+                    }
+                    Type::Array(item_type) => {
+                        if let Type::Reference(ref_name) = item_type.as_ref()
+                            && !objects.contains(ref_name)
+                        {
                             return Err(format!(
-                                "Undefined object reference '{}' in field '{}' of object '{}'",
+                                "Undefined object reference '{}' in array field '{}' of object '{}'",
                                 ref_name, field.name, object.id
                             ));
                         }
                     }
-                    Type::Array(item_type) => {
-                        if let Type::Reference(ref_name) = item_type.as_ref() {
-                            if !objects.contains(ref_name) {
-                                return Err(format!(
-                                    "Undefined object reference '{}' in array field '{}' of object '{}'",
-                                    ref_name, field.name, object.id
-                                ));
-                            }
-                        }
-                    }
                     Type::Mapping(key_type, value_type) => {
-                        if let Type::Reference(ref_name) = key_type.as_ref() {
-                            if !objects.contains(ref_name) {
-                                return Err(format!(
-                                    "Undefined object reference '{}' in mapping key of field '{}' of object '{}'",
-                                    ref_name, field.name, object.id
-                                ));
-                            }
+                        if let Type::Reference(ref_name) = key_type.as_ref()
+                            && !objects.contains(ref_name)
+                        {
+                            return Err(format!(
+                                "Undefined object reference '{}' in mapping key of field '{}' of object '{}'",
+                                ref_name, field.name, object.id
+                            ));
                         }
-                        if let Type::Reference(ref_name) = value_type.as_ref() {
-                            if !objects.contains(ref_name) {
-                                return Err(format!(
-                                    "Undefined object reference '{}' in mapping value of field '{}' of object '{}'",
-                                    ref_name, field.name, object.id
-                                ));
-                            }
+                        if let Type::Reference(ref_name) = value_type.as_ref()
+                            && !objects.contains(ref_name)
+                        {
+                            return Err(format!(
+                                "Undefined object reference '{}' in mapping value of field '{}' of object '{}'",
+                                ref_name, field.name, object.id
+                            ));
                         }
                     }
                     _ => {}
@@ -105,10 +231,19 @@ impl Schema {
             }
         }
 
+        for object in &self.objects {
+            if let Some(field) = self.required_cycle(&object.id) {
+                return Err(format!(
+                    "Object '{}' always contains itself through required field '{}', so it has no finite value",
+                    object.id, field
+                ));
+            }
+        }
+
         for endpoint in &self.endpoints {
             for param in &endpoint.params {
                 match &param.field_type {
-                    Type::String | Type::Integer | Type::Float | Type::Boolean  => {}
+                    Type::String | Type::Integer | Type::Float | Type::Boolean => {}
                     _ => {
                         return Err(format!(
                             "Invalid type for parameter '{}' in endpoint '{}'. Only primitive types are allowed.",
@@ -120,9 +255,9 @@ impl Schema {
 
             for query in &endpoint.query {
                 match &query.field_type {
-                    Type::String | Type::Integer | Type::Float | Type::Boolean  => {}
+                    Type::String | Type::Integer | Type::Float | Type::Boolean => {}
                     Type::Optional(t) => match t.as_ref() {
-                        Type::String | Type::Integer | Type::Float | Type::Boolean  => {}
+                        Type::String | Type::Integer | Type::Float | Type::Boolean => {}
                         _ => {
                             return Err(format!(
                                 "Invalid type for query parameter '{}' in endpoint '{}'. Only primitive types are allowed.",
@@ -139,23 +274,128 @@ impl Schema {
                 }
             }
 
-            if let Some(body_type) = &endpoint.request_body {
-                if let Type::Reference(ref_name) = body_type {
-                    if !objects.contains(ref_name) {
-                        return Err(format!(
-                            "Undefined object reference '{}' in request body of endpoint '{}'",
-                            ref_name, endpoint.id
-                        ));
-                    }
-                }
+            if let Some(body_type) = &endpoint.request_body
+                && let Type::Reference(ref_name) = body_type
+                && !objects.contains(ref_name)
+            {
+                return Err(format!(
+                    "Undefined object reference '{}' in request body of endpoint '{}'",
+                    ref_name, endpoint.id
+                ));
             }
 
             for (_, response_type) in &endpoint.responses {
-                if let Some(Type::Reference(ref_name)) = response_type {
-                    if !objects.contains(ref_name) {
+                if let Some(Type::Reference(ref_name)) = response_type
+                    && !objects.contains(ref_name)
+                {
+                    return Err(format!(
+                        "Undefined object reference '{}' in response of endpoint '{}'",
+                        ref_name, endpoint.id
+                    ));
+                }
+            }
+        }
+
+        self.verify_examples()
+    }
+
+    /// Finds a required field of `id` that leads back to `id` through required
+    /// object references only, returning its name.
+    fn required_cycle(&self, id: &str) -> Option<String> {
+        let required = |object_id: &str| -> Vec<(String, String)> {
+            self.get_object(object_id)
+                .map(|object| {
+                    object
+                        .fields
+                        .iter()
+                        .filter_map(|field| match &field.field_type {
+                            Type::Reference(target) => Some((field.name.clone(), target.clone())),
+                            _ => None,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        for (field, target) in required(id) {
+            let mut stack = vec![target];
+            let mut seen = std::collections::HashSet::new();
+
+            while let Some(current) = stack.pop() {
+                if current == id {
+                    return Some(field);
+                }
+                if seen.insert(current.clone()) {
+                    stack.extend(required(&current).into_iter().map(|(_, target)| target));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn verify_examples(&self) -> Result<(), String> {
+        for object in &self.objects {
+            for field in &object.fields {
+                if let Some(example) = &field.example {
+                    self.validate_value(example, &field.field_type)
+                        .map_err(|e| {
+                            format!(
+                                "Invalid example for field '{}' of object '{}': {}",
+                                field.name, object.id, e
+                            )
+                        })?;
+                }
+            }
+
+            if let Some(example) = &object.example {
+                self.validate_value(example, &Type::Reference(object.id.clone()))
+                    .map_err(|e| format!("Invalid example for object '{}': {}", object.id, e))?;
+            }
+        }
+
+        for endpoint in &self.endpoints {
+            for field in endpoint.params.iter().chain(endpoint.query.iter()) {
+                if let Some(example) = &field.example {
+                    self.validate_value(example, &field.field_type)
+                        .map_err(|e| {
+                            format!(
+                                "Invalid example for parameter '{}' in endpoint '{}': {}",
+                                field.name, endpoint.id, e
+                            )
+                        })?;
+                }
+            }
+
+            if let Some(example) = &endpoint.request_body_example
+                && let Some(body_type) = &endpoint.request_body
+            {
+                self.validate_value(example, body_type).map_err(|e| {
+                    format!(
+                        "Invalid request body example in endpoint '{}': {}",
+                        endpoint.id, e
+                    )
+                })?;
+            }
+
+            for (status, example) in &endpoint.response_examples {
+                let response_type = endpoint
+                    .responses
+                    .iter()
+                    .find(|(code, _)| code == status)
+                    .and_then(|(_, t)| t.as_ref());
+
+                match response_type {
+                    Some(t) => self.validate_value(example, t).map_err(|e| {
+                        format!(
+                            "Invalid example for response {} in endpoint '{}': {}",
+                            status, endpoint.id, e
+                        )
+                    })?,
+                    None => {
                         return Err(format!(
-                            "Undefined object reference '{}' in response of endpoint '{}'",
-                            ref_name, endpoint.id
+                            "Example given for response {} in endpoint '{}' which has no type",
+                            status, endpoint.id
                         ));
                     }
                 }
@@ -0,0 +1,324 @@
+use crate::{Endpoint, JsonValue, Schema};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SummaryFormat {
+    #[default]
+    Markdown,
+    Json,
+    /// One row per object and endpoint.
+    Csv,
+    /// One `statistic,key,value` row per statistic.
+    CsvStatistics,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SummaryOptions {
+    pub format: SummaryFormat,
+}
+
+fn endpoint_name(endpoint: &Endpoint) -> String {
+    format!("{} {}", endpoint.method.to_uppercase(), endpoint.path)
+}
+
+/// Counts items by key in order of first appearance.
+fn count_by(keys: impl Iterator<Item = String>) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for key in keys {
+        match counts.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((key, 1)),
+        }
+    }
+    counts
+}
+
+struct Statistics {
+    per_tag: Vec<(String, usize)>,
+    per_method: Vec<(String, usize)>,
+    untagged: usize,
+    undocumented: Vec<String>,
+}
+
+fn statistics(schema: &Schema) -> Statistics {
+    Statistics {
+        per_tag: count_by(schema.endpoints.iter().flat_map(|e| e.tags.iter().cloned())),
+        per_method: count_by(schema.endpoints.iter().map(|e| e.method.to_uppercase())),
+        untagged: schema
+            .endpoints
+            .iter()
+            .filter(|e| e.tags.is_empty())
+            .count(),
+        undocumented: schema
+            .endpoints
+            .iter()
+            .filter(|e| e.description.is_none())
+            .map(endpoint_name)
+            .collect(),
+    }
+}
+
+fn format_markdown(schema: &Schema) -> String {
+    let mut summary = String::new();
+
+    summary.push_str("# Summary\n\n## Objects\n\n");
+
+    for object in &schema.objects {
+        summary.push_str(&format!("- `{}`\n", object.id));
+    }
+
+    summary.push_str("\n## Endpoints\n\n");
+
+    for endpoint in &schema.endpoints {
+        summary.push_str(&format!("- `{}`\n", endpoint_name(endpoint)));
+    }
+
+    let stats = statistics(schema);
+    summary.push_str("\n## Statistics\n\n");
+    summary.push_str(&format!("- Objects: {}\n", schema.objects.len()));
+    summary.push_str(&format!("- Endpoints: {}\n", schema.endpoints.len()));
+    summary.push_str("- Endpoints per method:\n");
+    for (method, count) in &stats.per_method {
+        summary.push_str(&format!("  - `{}`: {}\n", method, count));
+    }
+    summary.push_str("- Endpoints per tag:\n");
+    for (tag, count) in &stats.per_tag {
+        summary.push_str(&format!("  - `{}`: {}\n", tag, count));
+    }
+    summary.push_str(&format!("  - Untagged: {}\n", stats.untagged));
+    summary.push_str(&format!(
+        "- Undocumented endpoints: {}\n",
+        stats.undocumented.len()
+    ));
+    for name in &stats.undocumented {
+        summary.push_str(&format!("  - `{}`\n", name));
+    }
+
+    summary
+}
+
+fn string(s: &str) -> JsonValue {
+    JsonValue::String(s.to_string())
+}
+
+fn integer(n: usize) -> JsonValue {
+    JsonValue::Integer(n as i64)
+}
+
+fn counts(counts: &[(String, usize)]) -> JsonValue {
+    JsonValue::Object(
+        counts
+            .iter()
+            .map(|(key, count)| (key.clone(), integer(*count)))
+            .collect(),
+    )
+}
+
+fn format_json(schema: &Schema) -> String {
+    let objects = schema
+        .objects
+        .iter()
+        .map(|object| {
+            JsonValue::Object(vec![
+                ("name".to_string(), string(&object.id)),
+                ("fields".to_string(), integer(object.fields.len())),
+            ])
+        })
+        .collect();
+
+    let endpoints = schema
+        .endpoints
+        .iter()
+        .map(|endpoint| {
+            JsonValue::Object(vec![
+                (
+                    "method".to_string(),
+                    string(&endpoint.method.to_uppercase()),
+                ),
+                ("path".to_string(), string(&endpoint.path)),
+                (
+                    "tags".to_string(),
+                    JsonValue::Array(endpoint.tags.iter().map(|t| string(t)).collect()),
+                ),
+                ("path_params".to_string(), integer(endpoint.params.len())),
+                ("query_params".to_string(), integer(endpoint.query.len())),
+                (
+                    "status_codes".to_string(),
+                    JsonValue::Array(
+                        endpoint
+                            .responses
+                            .iter()
+                            .map(|(status, _)| JsonValue::Integer(*status as i64))
+                            .collect(),
+                    ),
+                ),
+                (
+                    "documented".to_string(),
+                    JsonValue::Boolean(endpoint.description.is_some()),
+                ),
+            ])
+        })
+        .collect();
+
+    let stats = statistics(schema);
+    let statistics = JsonValue::Object(vec![
+        ("objects".to_string(), integer(schema.objects.len())),
+        ("endpoints".to_string(), integer(schema.endpoints.len())),
+        (
+            "endpoints_per_method".to_string(),
+            counts(&stats.per_method),
+        ),
+        ("endpoints_per_tag".to_string(), counts(&stats.per_tag)),
+        ("untagged_endpoints".to_string(), integer(stats.untagged)),
+        (
+            "undocumented_endpoints".to_string(),
+            JsonValue::Array(stats.undocumented.iter().map(|n| string(n)).collect()),
+        ),
+    ]);
+
+    let summary = JsonValue::Object(vec![
+        ("objects".to_string(), JsonValue::Array(objects)),
+        ("endpoints".to_string(), JsonValue::Array(endpoints)),
+        ("statistics".to_string(), statistics),
+    ]);
+    format!("{}\n", summary.to_pretty_json(0))
+}
+
+/// Quotes a CSV value if it contains a separator, quote or line break.
+fn csv_value(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn format_csv(schema: &Schema) -> String {
+    let mut rows = vec![
+        [
+            "kind",
+            "name",
+            "method",
+            "path",
+            "tags",
+            "fields",
+            "path_params",
+            "query_params",
+            "status_codes",
+            "documented",
+        ]
+        .map(String::from)
+        .to_vec(),
+    ];
+
+    for object in &schema.objects {
+        rows.push(vec![
+            "object".to_string(),
+            object.id.clone(),
+            String::new(),
+            String::new(),
+            String::new(),
+            object.fields.len().to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ]);
+    }
+
+    for endpoint in &schema.endpoints {
+        let statuses: Vec<String> = endpoint
+            .responses
+            .iter()
+            .map(|(status, _)| status.to_string())
+            .collect();
+
+        rows.push(vec![
+            "endpoint".to_string(),
+            endpoint_name(endpoint),
+            endpoint.method.to_uppercase(),
+            endpoint.path.clone(),
+            endpoint.tags.join(";"),
+            String::new(),
+            endpoint.params.len().to_string(),
+            endpoint.query.len().to_string(),
+            statuses.join(";"),
+            endpoint.description.is_some().to_string(),
+        ]);
+    }
+
+    format_csv_rows(&rows)
+}
+
+fn format_csv_statistics(schema: &Schema) -> String {
+    let stats = statistics(schema);
+    let mut rows = vec![
+        ["statistic", "key", "value"].map(String::from).to_vec(),
+        vec![
+            "objects".to_string(),
+            String::new(),
+            schema.objects.len().to_string(),
+        ],
+        vec![
+            "endpoints".to_string(),
+            String::new(),
+            schema.endpoints.len().to_string(),
+        ],
+    ];
+    for (method, count) in &stats.per_method {
+        rows.push(vec![
+            "endpoints_per_method".to_string(),
+            method.clone(),
+            count.to_string(),
+        ]);
+    }
+    for (tag, count) in &stats.per_tag {
+        rows.push(vec![
+            "endpoints_per_tag".to_string(),
+            tag.clone(),
+            count.to_string(),
+        ]);
+    }
+    rows.push(vec![
+        "untagged_endpoints".to_string(),
+        String::new(),
+        stats.untagged.to_string(),
+    ]);
+    rows.push(vec![
+        "undocumented_endpoints".to_string(),
+        String::new(),
+        stats.undocumented.len().to_string(),
+    ]);
+    for name in &stats.undocumented {
+        rows.push(vec![
+            "undocumented_endpoint".to_string(),
+            name.clone(),
+            String::new(),
+        ]);
+    }
+
+    format_csv_rows(&rows)
+}
+
+fn format_csv_rows(rows: &[Vec<String>]) -> String {
+    rows.iter()
+        .map(|row| {
+            let values: Vec<String> = row.iter().map(|v| csv_value(v)).collect();
+            format!("{}\n", values.join(","))
+        })
+        .collect()
+}
+
+/// Summarises the objects and endpoints of a specification, with statistics
+/// on endpoints per method and tag, and which endpoints lack a description.
+pub fn generate_summary(schema: &Schema) -> String {
+    generate_summary_with_options(schema, &SummaryOptions::default())
+}
+
+pub fn generate_summary_with_options(schema: &Schema, options: &SummaryOptions) -> String {
+    match options.format {
+        SummaryFormat::Markdown => format_markdown(schema),
+        SummaryFormat::Json => format_json(schema),
+        SummaryFormat::Csv => format_csv(schema),
+        SummaryFormat::CsvStatistics => format_csv_statistics(schema),
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt::Display;
+
+use crate::{Change, ChangeKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Display for Bump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Bump::None => "no bump",
+            Bump::Patch => "a patch bump",
+            Bump::Minor => "a minor bump",
+            Bump::Major => "a major bump",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+/// Parses a `MAJOR.MINOR.PATCH` version, ignoring a leading `v` and any
+/// pre-release or build metadata.
+fn parse_version(version: &str) -> Result<Version, String> {
+    let core = version
+        .strip_prefix('v')
+        .unwrap_or(version)
+        .split(['-', '+'])
+        .next()
+        .unwrap_or_default();
+
+    let parts: Vec<Option<u64>> = core.split('.').map(|p| p.parse().ok()).collect();
+    match parts.as_slice() {
+        [Some(major), Some(minor), Some(patch)] => Ok(Version {
+            major: *major,
+            minor: *minor,
+            patch: *patch,
+        }),
+        _ => Err(format!(
+            "Invalid version '{}', expected MAJOR.MINOR.PATCH",
+            version
+        )),
+    }
+}
+
+/// Returns the smallest version bump that covers the given changes.
+pub fn required_bump(changes: &[Change]) -> Bump {
+    changes
+        .iter()
+        .map(|change| match change.kind {
+            ChangeKind::Breaking => Bump::Major,
+            ChangeKind::NonBreaking => Bump::Minor,
+            ChangeKind::Documentation => Bump::Patch,
+        })
+        .max()
+        .unwrap_or(Bump::None)
+}
+
+/// Checks that the bump from `old` to `new` is at least as large as the one
+/// the changes require, returning the bump that was made.
+pub fn check_version(old: &str, new: &str, changes: &[Change]) -> Result<Bump, String> {
+    let old_version = parse_version(old)?;
+    let new_version = parse_version(new)?;
+
+    if new_version < old_version {
+        return Err(format!("Version decreased from {} to {}", old, new));
+    }
+
+    let bump = if new_version.major > old_version.major {
+        Bump::Major
+    } else if new_version.minor > old_version.minor {
+        Bump::Minor
+    } else if new_version.patch > old_version.patch {
+        Bump::Patch
+    } else {
+        Bump::None
+    };
+
+    let required = required_bump(changes);
+    if bump == Bump::None && required != Bump::None {
+        return Err(format!(
+            "Version {} is unchanged, but the changes require {}",
+            new, required
+        ));
+    }
+    if bump < required {
+        return Err(format!(
+            "Version {} to {} is {}, but the changes require {}",
+            old, new, bump, required
+        ));
+    }
+
+    Ok(bump)
+}
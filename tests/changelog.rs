@@ -0,0 +1,28 @@
+use manyleb::{DocsOptions, generate_changelog_with_options, generate_docs_with_options, parse};
+
+#[test]
+fn links_match_docs_rendered_with_the_same_options() {
+    let old = parse(r#"version "1.0.0""#, false).unwrap();
+    let new = parse(
+        r#"version "1.1.0"
+
+object Diagram {
+    prop id string "Identifier."
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let options = DocsOptions {
+        diagram: true,
+        ..DocsOptions::default()
+    };
+
+    let docs = generate_docs_with_options(&new, &options);
+    assert!(docs.contains("## Diagram\n"));
+    assert!(docs.contains("### Diagram\n"));
+
+    let changelog = generate_changelog_with_options(&[old, new], "api.md", &options);
+    assert!(changelog.contains("[`Diagram`](api.md#diagram-1)"));
+}
@@ -0,0 +1,129 @@
+use manyleb::{Change, ChangeKind, ChangeTarget, diff_schemas, parse};
+
+const BASE: &str = r#"object User {
+    prop id       string  "Identifier."
+    prop nickname ?string "Nickname."
+}
+
+route get "/users" {
+    description "Lists users."
+
+    query page ?integer "Page."
+
+    response 200 []User
+}
+"#;
+
+fn diff(old: &str, new: &str) -> Vec<Change> {
+    diff_schemas(&parse(old, false).unwrap(), &parse(new, false).unwrap())
+}
+
+fn object_change(kind: ChangeKind, message: &str) -> Change {
+    Change {
+        kind,
+        target: ChangeTarget::Object("User".to_string()),
+        message: message.to_string(),
+    }
+}
+
+fn endpoint_change(kind: ChangeKind, message: &str) -> Change {
+    Change {
+        kind,
+        target: ChangeTarget::Endpoint("GET".to_string(), "/users".to_string()),
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn identical_specifications_have_no_changes() {
+    assert_eq!(diff(BASE, BASE), vec![]);
+}
+
+#[test]
+fn removed_field_is_breaking() {
+    let new = BASE.replace("    prop nickname ?string \"Nickname.\"\n", "");
+
+    assert_eq!(
+        diff(BASE, &new),
+        vec![object_change(
+            ChangeKind::Breaking,
+            "Removed field 'nickname'"
+        )]
+    );
+}
+
+#[test]
+fn optional_field_made_required_is_breaking() {
+    let new = BASE.replace("?string", " string");
+
+    assert_eq!(
+        diff(BASE, &new),
+        vec![object_change(
+            ChangeKind::Breaking,
+            "Made field 'nickname' required"
+        )]
+    );
+}
+
+#[test]
+fn field_type_change_is_breaking() {
+    let new = BASE.replace("prop id       string ", "prop id       integer");
+
+    assert_eq!(
+        diff(BASE, &new),
+        vec![object_change(
+            ChangeKind::Breaking,
+            "Changed field 'id' from `string` to `integer`"
+        )]
+    );
+}
+
+#[test]
+fn new_required_query_parameter_is_breaking() {
+    let new = BASE.replace(
+        "    query page ?integer \"Page.\"\n",
+        "    query page ?integer \"Page.\"\n    query team string \"Team.\"\n",
+    );
+
+    assert_eq!(
+        diff(BASE, &new),
+        vec![endpoint_change(
+            ChangeKind::Breaking,
+            "Added required query parameter 'team'"
+        )]
+    );
+}
+
+#[test]
+fn new_optional_query_parameter_is_not_breaking() {
+    let new = BASE.replace(
+        "    query page ?integer \"Page.\"\n",
+        "    query page ?integer \"Page.\"\n    query team ?string \"Team.\"\n",
+    );
+
+    assert_eq!(
+        diff(BASE, &new),
+        vec![endpoint_change(
+            ChangeKind::NonBreaking,
+            "Added optional query parameter 'team'"
+        )]
+    );
+}
+
+#[test]
+fn description_change_is_documentation() {
+    let new = BASE
+        .replace("Lists users.", "Lists every user.")
+        .replace("\"Nickname.\"", "\"Display name.\"");
+
+    assert_eq!(
+        diff(BASE, &new),
+        vec![
+            object_change(
+                ChangeKind::Documentation,
+                "Changed description of field 'nickname'"
+            ),
+            endpoint_change(ChangeKind::Documentation, "Changed description"),
+        ]
+    );
+}
@@ -0,0 +1,47 @@
+use manyleb::{DocsOptions, generate_docs, generate_docs_with_options, parse};
+
+const SPEC: &str = r#"title "Docs"
+
+object User {
+    prop id string "Identifier."
+}
+
+route get "/users/:id" {
+    param id string "Identifier."
+
+    response 200 User
+}
+"#;
+
+#[test]
+fn table_of_contents_is_opt_in() {
+    let schema = parse(SPEC, false).unwrap();
+
+    let docs = generate_docs(&schema);
+    assert!(!docs.contains("Table of Contents"));
+
+    let options = DocsOptions {
+        table_of_contents: true,
+        ..DocsOptions::default()
+    };
+    let docs = generate_docs_with_options(&schema, &options);
+    assert!(docs.contains("## Table of Contents\n"));
+    assert!(docs.contains("[User](#user)"));
+}
+
+#[test]
+fn escapes_angle_brackets_in_tables() {
+    let schema = parse(
+        r#"object Scores {
+    prop byUser <string, integer> "Scores keyed by <user id>." example {"a|b": 1}
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let docs = generate_docs(&schema);
+    assert!(docs.contains(
+        "| byUser | &lt;string, integer&gt; | Scores keyed by &lt;user id&gt;. | `{\"a\\|b\":1}` |\n"
+    ));
+}
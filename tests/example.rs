@@ -0,0 +1,63 @@
+use manyleb::{ExampleOptions, Type, generate_example, parse};
+
+#[test]
+fn recursive_examples_validate() {
+    let schema = parse(
+        r#"object Node {
+    prop name     string          "Name."
+    prop parent   ?Node           "Parent."
+    prop children []Node          "Children."
+    prop links    <string, Node>  "Linked nodes."
+    prop owner    Owner           "Owner."
+}
+
+object Owner {
+    prop name  string "Name."
+    prop nodes []Node "Nodes."
+    prop home  ?Node  "Home node."
+}
+"#,
+        false,
+    )
+    .unwrap();
+    schema.verify().unwrap();
+
+    for max_depth in 0..4 {
+        for seed in 0..16 {
+            for include_optional in [false, true] {
+                let options = ExampleOptions {
+                    seed,
+                    max_depth,
+                    include_optional,
+                    ..ExampleOptions::default()
+                };
+                for id in ["Node", "Owner"] {
+                    let t = Type::Reference(id.to_string());
+                    let example = generate_example(&schema, &t, &options);
+                    schema.validate_value(&example, &t).unwrap();
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn rejects_objects_that_always_contain_themselves() {
+    let schema = parse(
+        r#"object Node {
+    prop child Child "Child."
+}
+
+object Child {
+    prop parent Node "Parent."
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(
+        schema.verify().unwrap_err(),
+        "Object 'Node' always contains itself through required field 'child', so it has no finite value"
+    );
+}
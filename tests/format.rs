@@ -0,0 +1,50 @@
+use manyleb::{Schema, format, parse};
+
+fn examples(schema: &Schema) -> Vec<String> {
+    let mut examples = Vec::new();
+    for object in &schema.objects {
+        examples.extend(object.fields.iter().map(|f| format!("{:?}", f.example)));
+        examples.push(format!("{:?}", object.example));
+    }
+    for endpoint in &schema.endpoints {
+        examples.push(format!("{:?}", endpoint.request_body_example));
+        examples.push(format!("{:?}", endpoint.response_examples));
+    }
+    examples
+}
+
+#[test]
+fn format_round_trips() {
+    let input = r#"title "Round \"trip\"\tcheck"
+
+object Values {
+    prop big     float  "Large."        example 1e20
+    prop small   float  "Small."        example -1.5e-7
+    prop whole   float  "Whole."        example 3.0
+    prop control string "Control."      example "bell\u0007 and é and 😀"
+    prop nested  any    "Nested value." example {"a": [1, 2.5, null, true], "b": "x"}
+
+    example {
+        "big": 1e300,
+        "small": 5e-324,
+        "whole": 0.0,
+        "control": "\u0001",
+        "nested": {}
+    }
+}
+
+route post "/values" {
+    body Values example { "big": 12345678901234567.0, "small": 0.00001, "whole": -0.5, "control": "", "nested": [] }
+
+    response 200 Values
+}
+"#;
+
+    let schema = parse(input, false).unwrap();
+    let formatted = format(&schema);
+    let reparsed = parse(&formatted, false).unwrap();
+
+    assert_eq!(examples(&schema), examples(&reparsed));
+    assert_eq!(schema.title, reparsed.title);
+    assert_eq!(formatted, format(&reparsed));
+}
@@ -0,0 +1,165 @@
+use manyleb::{
+    generate_go_client, generate_kotlin, generate_rust_client, generate_swift, generate_typescript,
+    generate_typescript_client, generate_zod, parse,
+};
+
+const COLLISIONS: &str = r#"object Pair {
+    prop fooBar  string "Camel case."
+    prop foo_bar string "Snake case."
+}
+
+route get "/user/list" {
+    query pageSize  integer "Camel case."
+    query page_size integer "Snake case."
+
+    response 200 Pair
+}
+
+route get "/userList" {
+    response 200 Pair
+}
+"#;
+
+#[test]
+fn numbers_colliding_endpoint_names() {
+    let schema = parse(COLLISIONS, false).unwrap();
+
+    let typescript = generate_typescript(&schema);
+    assert!(typescript.contains("export interface GetUserListResponses {"));
+    assert!(typescript.contains("export interface GetUserList2Responses {"));
+
+    let rust = generate_rust_client(&schema);
+    assert!(rust.contains("pub async fn get_user_list(\n"));
+    assert!(rust.contains("pub async fn get_user_list2(\n"));
+}
+
+#[test]
+fn numbers_colliding_field_identifiers() {
+    let schema = parse(COLLISIONS, false).unwrap();
+
+    let rust = generate_rust_client(&schema);
+    assert!(rust.contains("#[serde(rename = \"fooBar\")]\n    pub foo_bar: String,"));
+    assert!(rust.contains("#[serde(rename = \"foo_bar\")]\n    pub foo_bar_2: String,"));
+    assert!(rust.contains("pub page_size_2: i64,"));
+
+    let go = generate_go_client(&schema);
+    assert!(go.contains("FooBar  string `json:\"fooBar\"`"));
+    assert!(go.contains("FooBar2 string `json:\"foo_bar\"`"));
+    assert!(go.contains("values.Set(\"page_size\", fmt.Sprint(query.PageSize2))"));
+
+    let kotlin = generate_kotlin(&schema);
+    assert!(kotlin.contains("    val fooBar: String,"));
+    assert!(kotlin.contains("    @SerialName(\"foo_bar\")\n    val fooBar2: String,"));
+}
+
+#[test]
+fn zod_coerces_path_and_query_parameters() {
+    let schema = parse(
+        r#"object Filter {
+    prop limit  integer "Body fields stay strict."
+    prop active boolean "Body fields stay strict."
+}
+
+route post "/items/:id" {
+    param id integer "Path parameter."
+
+    query active ?boolean "Query parameter."
+    query ratio  float    "Query parameter."
+
+    body Filter
+
+    response 204
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let zod = generate_zod(&schema);
+    assert!(zod.contains("    id: z.coerce.number().int(),\n"));
+    assert!(zod.contains(
+        "    active: z.preprocess((v) => (v === \"true\" ? true : v === \"false\" ? false : v), z.boolean()).nullish(),\n"
+    ));
+    assert!(zod.contains("    ratio: z.coerce.number(),\n"));
+    assert!(zod.contains("    limit: z.number().int(),\n"));
+    assert!(zod.contains("    active: z.boolean(),\n"));
+}
+
+#[test]
+fn swift_keeps_recursive_objects_as_structs() {
+    let schema = parse(
+        r#"object Node {
+    prop parent   ?Node  "Parent."
+    prop children []Node "Children."
+    prop nothing  null   "Always null."
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let swift = generate_swift(&schema);
+    assert!(swift.contains("public struct Node: Codable {"));
+    assert!(!swift.contains("class Node"));
+    assert!(swift.contains("    @Indirect\n    public var parent: Node?\n"));
+    assert!(swift.contains("    public var children: [Node]\n"));
+    assert!(swift.contains("    public var nothing: JSONNull?\n"));
+    assert!(swift.contains("public struct JSONNull: Codable, Equatable {"));
+    assert!(!swift.contains("Never"));
+}
+
+#[test]
+fn typescript_client_escapes_reserved_function_names() {
+    let schema = parse(
+        r#"route delete "/" {
+    response 204
+}
+
+route get "/" {
+    response 204
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let typescript = generate_typescript_client(&schema);
+    assert!(typescript.contains("export async function delete_(\n"));
+    assert!(!typescript.contains("function delete("));
+    assert!(typescript.contains("export async function get(\n"));
+}
+
+#[test]
+fn go_client_decodes_each_success_status_into_its_own_type() {
+    let schema = parse(
+        r#"object User {
+    prop id string "Identifier."
+}
+
+object Job {
+    prop id string "Identifier."
+}
+
+route post "/users" {
+    body ?User
+
+    response 200 User
+    response 202 Job
+    response 204
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let go = generate_go_client(&schema);
+    assert!(go.contains("\tStatus200  *User\n"));
+    assert!(go.contains("\tStatus202  *Job\n"));
+    assert!(go.contains("json.Unmarshal(data, &result.Status200)"));
+    assert!(go.contains("json.Unmarshal(data, &result.Status202)"));
+    assert!(go.contains("\tcase 204:\n\t\tresult := &PostUsersResponse{StatusCode: status, Body: data}\n\t\treturn result, nil\n"));
+    assert!(!go.contains("case 200, 202"));
+
+    assert!(go.contains("\tvar payload any\n\tif body != nil {\n\t\tpayload = body\n\t}\n"));
+    assert!(go.contains("c.do(ctx, \"POST\", \"/users\", values, payload)"));
+}
@@ -0,0 +1,46 @@
+use manyleb::JsonValue;
+
+#[test]
+fn combines_surrogate_pairs() {
+    assert_eq!(
+        JsonValue::parse(r#""\uD83D\uDE00 \u00e9""#),
+        Ok(JsonValue::String("\u{1F600} \u{e9}".to_string()))
+    );
+}
+
+#[test]
+fn rejects_unpaired_surrogates() {
+    for input in [r#""\uD83D""#, r#""\uD83Dx""#, r#""\uD83DA""#, r#""\uDE00""#] {
+        assert!(JsonValue::parse(input).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn parses_numbers() {
+    for (input, expected) in [
+        ("0", JsonValue::Integer(0)),
+        ("-0", JsonValue::Integer(0)),
+        ("42", JsonValue::Integer(42)),
+        ("-17", JsonValue::Integer(-17)),
+        ("0.5", JsonValue::Float(0.5)),
+        ("-1.25", JsonValue::Float(-1.25)),
+        ("1e3", JsonValue::Float(1000.0)),
+        ("2.5E-1", JsonValue::Float(0.25)),
+        ("1e+2", JsonValue::Float(100.0)),
+        (
+            "[1,2]",
+            JsonValue::Array(vec![JsonValue::Integer(1), JsonValue::Integer(2)]),
+        ),
+    ] {
+        assert_eq!(JsonValue::parse(input), Ok(expected), "{}", input);
+    }
+}
+
+#[test]
+fn rejects_numbers_outside_the_json_grammar() {
+    for input in [
+        "01", "-01", "00", "1.", "-", ".5", "1e", "1e+", "1.e3", "+1", "1.5.3", "1-2", "0x10",
+    ] {
+        assert!(JsonValue::parse(input).is_err(), "{}", input);
+    }
+}
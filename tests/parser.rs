@@ -0,0 +1,89 @@
+use manyleb::{JsonValue, parse};
+
+fn prop_example(example: &str) -> Result<Option<JsonValue>, String> {
+    let input = format!(
+        "object A {{\n    prop a any \"A.\" example {}\n}}\n",
+        example
+    );
+    let schema = parse(&input, false)?;
+    Ok(schema.objects[0].fields[0].example.clone())
+}
+
+#[test]
+fn parses_numbers() {
+    assert_eq!(prop_example("-7"), Ok(Some(JsonValue::Integer(-7))));
+    assert_eq!(prop_example("1.5"), Ok(Some(JsonValue::Float(1.5))));
+    assert_eq!(prop_example("1e20"), Ok(Some(JsonValue::Float(1e20))));
+    assert_eq!(prop_example("-2.5E-3"), Ok(Some(JsonValue::Float(-2.5e-3))));
+}
+
+#[test]
+fn rejects_malformed_numbers_with_position() {
+    for number in ["1-2", "--", "-", "1e", "3.", "12abc"] {
+        assert_eq!(
+            prop_example(number),
+            Err(format!(
+                "Error at line 2, column 29: Invalid number '{}'",
+                number
+            )),
+        );
+    }
+
+    assert_eq!(
+        prop_example("99999999999999999999"),
+        Err(
+            "Error at line 2, column 29: Integer '99999999999999999999' is out of range"
+                .to_string()
+        ),
+    );
+}
+
+#[test]
+fn parses_object_example_after_last_prop() {
+    // The object example snippet from the README.
+    let input = r#"object User {
+    prop id   string   "Unique identifier for the user"
+    prop age  ?integer "Age of the user"
+
+    example {
+        "id": "u_123",
+        "age": 42
+    }
+}
+"#;
+
+    let schema = parse(input, false).unwrap();
+    schema.verify().unwrap();
+
+    let user = &schema.objects[0];
+    assert_eq!(user.fields[1].example, None);
+    assert_eq!(
+        user.example,
+        Some(JsonValue::Object(vec![
+            ("id".to_string(), JsonValue::String("u_123".to_string())),
+            ("age".to_string(), JsonValue::Integer(42)),
+        ]))
+    );
+}
+
+#[test]
+fn parses_example_as_a_name() {
+    let input = r#"object example {
+    prop example string "A prop named example." example "value"
+}
+
+route get "/examples" {
+    response 200 example
+}
+"#;
+
+    let schema = parse(input, false).unwrap();
+    schema.verify().unwrap();
+
+    assert_eq!(schema.objects[0].id, "example");
+    assert_eq!(schema.objects[0].fields[0].name, "example");
+    assert_eq!(
+        schema.objects[0].fields[0].example,
+        Some(JsonValue::String("value".to_string()))
+    );
+}
@@ -0,0 +1,22 @@
+use manyleb::{generate_postman, parse};
+
+#[test]
+fn encodes_query_values_in_raw_url() {
+    let schema = parse(
+        r#"route get "/search" {
+    query q    string "Search terms." example "a&b=c #1 é"
+    query page integer "Page."       example 2
+
+    response 204
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let collection = generate_postman(&schema).to_json();
+    assert!(
+        collection.contains(r#""raw":"{{baseUrl}}/search?q=a%26b%3Dc%20%231%20%C3%A9&page=2""#)
+    );
+    assert!(collection.contains(r#""key":"q","value":"a&b=c #1 é""#));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "serde")]
+
+use manyleb::{Schema, format, parse};
+
+#[test]
+fn json_round_trips_null_examples() {
+    let input = r#"object User {
+    prop name     string  "Name."     example "Ada"
+    prop nickname ?string "Nickname." example null
+    prop age      ?integer "Age."
+
+    example {
+        "name": "Ada",
+        "nickname": null
+    }
+}
+
+route post "/users" {
+    body ?User example null
+
+    response 201 User
+}
+"#;
+    let schema = parse(input, false).unwrap();
+
+    let json = serde_json::to_string(&schema).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let fields = &value["objects"][0]["fields"];
+    assert_eq!(fields[1]["example"], serde_json::Value::Null);
+    assert!(fields[2].get("example").is_none());
+
+    let reparsed: Schema = serde_json::from_str(&json).unwrap();
+    let field = &reparsed.objects[0].fields[1];
+    assert_eq!(format!("{:?}", field.example), "Some(Null)");
+    assert!(reparsed.objects[0].fields[2].example.is_none());
+    assert_eq!(
+        format!("{:?}", reparsed.endpoints[0].request_body_example),
+        "Some(Null)"
+    );
+    assert_eq!(format(&reparsed), format(&schema));
+}
@@ -0,0 +1,79 @@
+use manyleb::{SummaryFormat, SummaryOptions, generate_summary_with_options, parse};
+
+#[test]
+fn csv_statistics_lists_every_statistic() {
+    let schema = parse(
+        r#"object User {
+    prop id string "Identifier."
+}
+
+route get "/users" {
+    description "Lists users."
+
+    tag "Users"
+
+    response 200 []User
+}
+
+route post "/users" {
+    tag "Users"
+    tag "Admin"
+
+    body User
+
+    response 201 User
+}
+
+route get "/health" {
+    response 204
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let options = SummaryOptions {
+        format: SummaryFormat::CsvStatistics,
+    };
+    let statistics = generate_summary_with_options(&schema, &options);
+
+    assert_eq!(
+        statistics,
+        "statistic,key,value
+objects,,1
+endpoints,,3
+endpoints_per_method,GET,2
+endpoints_per_method,POST,1
+endpoints_per_tag,Users,2
+endpoints_per_tag,Admin,1
+untagged_endpoints,,1
+undocumented_endpoints,,2
+undocumented_endpoint,POST /users,
+undocumented_endpoint,GET /health,
+"
+    );
+}
+
+#[test]
+fn csv_summary_is_a_single_table() {
+    let schema = parse(
+        r#"route get "/health" {
+    response 204
+}
+"#,
+        false,
+    )
+    .unwrap();
+
+    let options = SummaryOptions {
+        format: SummaryFormat::Csv,
+    };
+    let summary = generate_summary_with_options(&schema, &options);
+
+    assert_eq!(
+        summary,
+        "kind,name,method,path,tags,fields,path_params,query_params,status_codes,documented
+endpoint,GET /health,GET,/health,,,0,0,204,false
+"
+    );
+}
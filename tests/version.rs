@@ -0,0 +1,116 @@
+use manyleb::{Bump, Change, ChangeKind, ChangeTarget, check_version, required_bump};
+
+fn changes(kinds: &[ChangeKind]) -> Vec<Change> {
+    kinds
+        .iter()
+        .map(|kind| Change {
+            kind: *kind,
+            target: ChangeTarget::Schema,
+            message: "Changed something".to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn required_bump_is_the_largest_change() {
+    use ChangeKind::*;
+
+    for (kinds, expected) in [
+        (&[][..], Bump::None),
+        (&[Documentation][..], Bump::Patch),
+        (&[Documentation, NonBreaking][..], Bump::Minor),
+        (&[NonBreaking, Breaking, Documentation][..], Bump::Major),
+    ] {
+        assert_eq!(required_bump(&changes(kinds)), expected, "{:?}", kinds);
+    }
+}
+
+#[test]
+fn check_version_accepts_large_enough_bumps() {
+    use ChangeKind::*;
+
+    for (old, new, kinds, expected) in [
+        ("1.0.0", "1.0.0", &[][..], Bump::None),
+        ("1.0.0", "1.0.1", &[Documentation][..], Bump::Patch),
+        ("1.0.0", "1.1.0", &[NonBreaking][..], Bump::Minor),
+        ("1.0.0", "1.1.0", &[Documentation][..], Bump::Minor),
+        ("1.4.2", "2.0.0", &[Breaking, NonBreaking][..], Bump::Major),
+        ("0.3.1", "1.0.0", &[Breaking][..], Bump::Major),
+        ("0.3.1", "0.4.0", &[NonBreaking][..], Bump::Minor),
+        ("v1.2.3", "v1.2.4-beta.1", &[Documentation][..], Bump::Patch),
+    ] {
+        assert_eq!(
+            check_version(old, new, &changes(kinds)),
+            Ok(expected),
+            "{} -> {} with {:?}",
+            old,
+            new,
+            kinds
+        );
+    }
+}
+
+#[test]
+fn check_version_rejects_small_bumps_and_invalid_versions() {
+    use ChangeKind::*;
+
+    for (old, new, kinds, error) in [
+        (
+            "1.0.0",
+            "1.1.0",
+            &[Breaking][..],
+            "Version 1.0.0 to 1.1.0 is a minor bump, but the changes require a major bump",
+        ),
+        (
+            "1.0.0",
+            "1.0.1",
+            &[NonBreaking, Documentation][..],
+            "Version 1.0.0 to 1.0.1 is a patch bump, but the changes require a minor bump",
+        ),
+        (
+            "0.3.1",
+            "0.4.0",
+            &[Breaking][..],
+            "Version 0.3.1 to 0.4.0 is a minor bump, but the changes require a major bump",
+        ),
+        (
+            "1.0.0",
+            "1.0.0",
+            &[Documentation][..],
+            "Version 1.0.0 is unchanged, but the changes require a patch bump",
+        ),
+        (
+            "1.1.0",
+            "1.0.5",
+            &[][..],
+            "Version decreased from 1.1.0 to 1.0.5",
+        ),
+        (
+            "",
+            "1.0.0",
+            &[][..],
+            "Invalid version '', expected MAJOR.MINOR.PATCH",
+        ),
+        (
+            "1.0.0",
+            "1.1",
+            &[NonBreaking][..],
+            "Invalid version '1.1', expected MAJOR.MINOR.PATCH",
+        ),
+        (
+            "1.0.0",
+            "latest",
+            &[][..],
+            "Invalid version 'latest', expected MAJOR.MINOR.PATCH",
+        ),
+    ] {
+        assert_eq!(
+            check_version(old, new, &changes(kinds)),
+            Err(error.to_string()),
+            "{} -> {} with {:?}",
+            old,
+            new,
+            kinds
+        );
+    }
+}